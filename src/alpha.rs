@@ -0,0 +1,803 @@
+use crate::*;
+
+//
+// Colors to alpha colors
+//
+
+impl From<DigitalGrayscaleColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        DigitalGrayscaleAlphaColor{
+            v: f.v,
+            a: 255
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        NormalizedGrayscaleAlphaColor{
+            v: f.v,
+            a: 1.0
+        }
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCbCrAColor {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        DigitalYCbCrAColor{
+            y: f.y,
+            cb: f.cb,
+            cr: f.cr,
+            a: 255
+        }
+    }
+}
+
+impl From<NormalizedYCbCrColor> for NormalizedYCbCrAColor {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        NormalizedYCbCrAColor{
+            y: f.y,
+            cb: f.cb,
+            cr: f.cr,
+            a: 1.0
+        }
+    }
+}
+
+impl From<CIELabColor> for CIELabAColor {
+    fn from(f: CIELabColor) -> Self {
+        CIELabAColor{
+            l: f.l,
+            a: f.a,
+            b: f.b,
+            alpha: 1.0
+        }
+    }
+}
+
+impl From<CIEXYZColor> for CIEXYZAColor {
+    fn from(f: CIEXYZColor) -> Self {
+        CIEXYZAColor{
+            x: f.x,
+            y: f.y,
+            z: f.z,
+            alpha: 1.0
+        }
+    }
+}
+
+//
+// RGBA to alpha colors
+//
+
+impl From<DigitalRGBAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(f);
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(f);
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(f);
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(f);
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalYCbCrAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = DigitalYCbCrColor::from(f);
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for DigitalYCbCrAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = DigitalYCbCrColor::from(f);
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for NormalizedYCbCrAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = NormalizedYCbCrColor::from(f);
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for NormalizedYCbCrAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = NormalizedYCbCrColor::from(f);
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for CIELabAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = CIELabColor::from(f);
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for CIELabAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = CIELabColor::from(f);
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for CIEXYZAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        let c = CIEXYZColor::from(f);
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for CIEXYZAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let c = CIEXYZColor::from(f);
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a
+        }
+    }
+}
+
+//
+// Alpha colors to alpha colors
+//
+
+impl From<NormalizedGrayscaleAlphaColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = DigitalGrayscaleColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIELabAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for DigitalGrayscaleAlphaColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = DigitalGrayscaleColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        DigitalGrayscaleAlphaColor{
+            v: c.v,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.a
+        }
+    }
+}
+
+impl From<CIELabAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.alpha
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for NormalizedGrayscaleAlphaColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = NormalizedGrayscaleColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        NormalizedGrayscaleAlphaColor{
+            v: c.v,
+            a: f.alpha
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for DigitalYCbCrAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = DigitalYCbCrColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for DigitalYCbCrAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = DigitalYCbCrColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for DigitalYCbCrAColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = DigitalYCbCrColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIELabAColor> for DigitalYCbCrAColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = DigitalYCbCrColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for DigitalYCbCrAColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = DigitalYCbCrColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        DigitalYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for NormalizedYCbCrAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = NormalizedYCbCrColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for NormalizedYCbCrAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = NormalizedYCbCrColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for NormalizedYCbCrAColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = NormalizedYCbCrColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<CIELabAColor> for NormalizedYCbCrAColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = NormalizedYCbCrColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.alpha
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for NormalizedYCbCrAColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = NormalizedYCbCrColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        NormalizedYCbCrAColor{
+            y: c.y,
+            cb: c.cb,
+            cr: c.cr,
+            a: f.alpha
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for CIELabAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = CIELabColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for CIELabAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = CIELabColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for CIELabAColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = CIELabColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for CIELabAColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = CIELabColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.a
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for CIELabAColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = CIELabColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        CIELabAColor{
+            l: c.l,
+            a: c.a,
+            b: c.b,
+            alpha: f.alpha
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for CIEXYZAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = CIEXYZColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for CIEXYZAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = CIEXYZColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for CIEXYZAColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = CIEXYZColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for CIEXYZAColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = CIEXYZColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.a
+        }
+    }
+}
+
+impl From<CIELabAColor> for CIEXYZAColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = CIEXYZColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        CIEXYZAColor{
+            x: c.x,
+            y: c.y,
+            z: c.z,
+            alpha: f.alpha
+        }
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl YCbCrAConvertible for DigitalGrayscaleAlphaColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for DigitalGrayscaleAlphaColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for DigitalGrayscaleAlphaColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for DigitalGrayscaleAlphaColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for NormalizedGrayscaleAlphaColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for NormalizedGrayscaleAlphaColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for NormalizedGrayscaleAlphaColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for NormalizedGrayscaleAlphaColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedGrayscaleAlphaColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for DigitalYCbCrAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for DigitalYCbCrAColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for DigitalYCbCrAColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for DigitalYCbCrAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for NormalizedYCbCrAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for NormalizedYCbCrAColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for NormalizedYCbCrAColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for NormalizedYCbCrAColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedYCbCrAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for CIELabAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for CIELabAColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for CIELabAColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for CIELabAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for CIEXYZAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for CIEXYZAColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for CIEXYZAColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for CIEXYZAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rgba_2_laba_carries_alpha() {
+        let rgba = DigitalRGBAColor{ r: 255, g: 255, b: 255, a: 51 };
+        let lab = CIELabAColor::from(rgba);
+        assert!((lab.l - 100.0).abs() < 0.01, "{:?}", lab);
+        assert_eq!(0.2, lab.alpha);
+
+        let back = DigitalRGBAColor::from(lab);
+        assert_eq!(51, back.a);
+    }
+
+    #[test]
+    fn rgba_2_gray_alpha() {
+        let rgba = DigitalRGBAColor{ r: 100, g: 150, b: 200, a: 7 };
+        assert_eq!(DigitalGrayscaleAlphaColor{ v: 141, a: 7 }, DigitalGrayscaleAlphaColor::from(rgba));
+        let rgba = NormalizedRGBAColor{ r: 1.0, g: 1.0, b: 1.0, a: 0.5 };
+        assert_eq!(DigitalGrayscaleAlphaColor{ v: 255, a: 128 }, DigitalGrayscaleAlphaColor::from(rgba));
+    }
+
+    #[test]
+    fn ycbcra_2_rgba() {
+        let ycbcr = DigitalYCbCrAColor{ y: 0, cb: 128, cr: 128, a: 0 };
+        assert_eq!(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 }, DigitalRGBAColor::from(ycbcr));
+    }
+
+    #[test]
+    fn laba_2_xyza() {
+        let lab = CIELabAColor{ l: 50.0, a: 20.0, b: -30.0, alpha: 0.3 };
+        let xyz = CIEXYZAColor::from(lab);
+        let expected = CIEXYZColor::from(CIELabColor{ l: 50.0, a: 20.0, b: -30.0 });
+        assert_eq!(CIEXYZAColor{ x: expected.x, y: expected.y, z: expected.z, alpha: 0.3 }, xyz);
+        assert_eq!(77, DigitalGrayscaleAlphaColor::from(xyz).a);
+    }
+
+    #[test]
+    fn opaque_colors() {
+        let gray = NormalizedGrayscaleAlphaColor::from(NormalizedGrayscaleColor{ v: 0.5 });
+        assert_eq!(NormalizedGrayscaleAlphaColor{ v: 0.5, a: 1.0 }, gray);
+        assert_eq!(255, DigitalYCbCrAColor::from(gray).a);
+    }
+}
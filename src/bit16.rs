@@ -0,0 +1,428 @@
+use crate::*;
+use crate::packed::{field, replicate};
+
+/// Expand a `u8` value to the full `u16` range
+#[inline]
+pub(crate) fn u8_to_u16(v: u8) -> u16 {
+    v as u16 * 257
+}
+
+/// Reduce a `u16` value to `u8` with correct rounding
+#[inline]
+pub(crate) fn u16_to_u8(v: u16) -> u8 {
+    ((v as u32 + 128) / 257) as u8
+}
+
+/// Quantize a normalized value to `u16`, out of range values are clipped
+#[inline]
+pub(crate) fn f32_to_u16(v: f32) -> u16 {
+    (v * 65535.0).round().clamp(0.0, 65535.0) as u16
+}
+
+/// Normalize a `u16` value
+#[inline]
+pub(crate) fn u16_to_f32(v: u16) -> f32 {
+    v as f32 / 65535.0
+}
+
+//
+// Native types to 16 bit colors
+//
+
+impl From<[u16; 3]> for DigitalRGB16Color {
+    fn from(f: [u16; 3]) -> Self {
+        DigitalRGB16Color{
+            r: f[0],
+            g: f[1],
+            b: f[2]
+        }
+    }
+}
+
+impl From<[u16; 4]> for DigitalRGBA16Color {
+    fn from(f: [u16; 4]) -> Self {
+        DigitalRGBA16Color{
+            r: f[0],
+            g: f[1],
+            b: f[2],
+            a: f[3]
+        }
+    }
+}
+
+impl From<[u16; 3]> for DigitalYCbCr16Color {
+    fn from(f: [u16; 3]) -> Self {
+        DigitalYCbCr16Color{
+            y: f[0],
+            cb: f[1],
+            cr: f[2]
+        }
+    }
+}
+
+impl From<DigitalRGB16Color> for [u16; 3] {
+    fn from(f: DigitalRGB16Color) -> Self {
+        [f.r, f.g, f.b]
+    }
+}
+
+impl From<DigitalRGBA16Color> for [u16; 4] {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        [f.r, f.g, f.b, f.a]
+    }
+}
+
+impl From<DigitalYCbCr16Color> for [u16; 3] {
+    fn from(f: DigitalYCbCr16Color) -> Self {
+        [f.y, f.cb, f.cr]
+    }
+}
+
+//
+// RGB to RGB16
+//
+
+impl From<NormalizedRGBColor> for DigitalRGB16Color {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalRGB16Color{
+            r: f32_to_u16(f.r),
+            g: f32_to_u16(f.g),
+            b: f32_to_u16(f.b)
+        }
+    }
+}
+
+impl From<DigitalRGBColor> for DigitalRGB16Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        DigitalRGB16Color{
+            r: u8_to_u16(f.r),
+            g: u8_to_u16(f.g),
+            b: u8_to_u16(f.b)
+        }
+    }
+}
+
+impl From<DigitalRGBA16Color> for DigitalRGB16Color {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        DigitalRGB16Color{
+            r: f.r,
+            g: f.g,
+            b: f.b
+        }
+    }
+}
+
+impl From<DigitalGrayscale16Color> for DigitalRGB16Color {
+    fn from(f: DigitalGrayscale16Color) -> Self {
+        DigitalRGB16Color{
+            r: f.v,
+            g: f.v,
+            b: f.v
+        }
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalYCbCr16Color> for DigitalRGB16Color {
+    fn from(f: DigitalYCbCr16Color) -> Self {
+        DigitalRGB16Color::from(NormalizedRGBColor::from(NormalizedYCbCrColor::from(f)))
+    }
+}
+
+impl DigitalRGB16Color {
+    /// Convert a YCbCr color with `bit_depth` bits quantized to `range` using the specified `matrix`
+    pub fn from_ycbcr(f: DigitalYCbCr16Color, matrix: YCbCrMatrix, range: YCbCrRange, bit_depth: u32) -> Self {
+        DigitalRGB16Color::from(NormalizedRGBColor::from_ycbcr(
+            NormalizedYCbCrColor::from_digital16(f, range, bit_depth),
+            matrix
+        ))
+    }
+}
+
+//
+// RGBA to RGBA16
+//
+
+impl From<NormalizedRGBAColor> for DigitalRGBA16Color {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalRGBA16Color{
+            r: f32_to_u16(f.r),
+            g: f32_to_u16(f.g),
+            b: f32_to_u16(f.b),
+            a: f32_to_u16(f.a)
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalRGBA16Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalRGBA16Color{
+            r: u8_to_u16(f.r),
+            g: u8_to_u16(f.g),
+            b: u8_to_u16(f.b),
+            a: u8_to_u16(f.a)
+        }
+    }
+}
+
+impl From<DigitalRGB16Color> for DigitalRGBA16Color {
+    fn from(f: DigitalRGB16Color) -> Self {
+        DigitalRGBA16Color{
+            r: f.r,
+            g: f.g,
+            b: f.b,
+            a: 65535
+        }
+    }
+}
+
+impl From<DigitalGrayscale16Color> for DigitalRGBA16Color {
+    fn from(f: DigitalGrayscale16Color) -> Self {
+        DigitalRGBA16Color{
+            r: f.v,
+            g: f.v,
+            b: f.v,
+            a: 65535
+        }
+    }
+}
+
+impl From<PackedRGB10A2Color> for DigitalRGBA16Color {
+    fn from(f: PackedRGB10A2Color) -> Self {
+        DigitalRGBA16Color{
+            r: replicate(field(f.0, 0, 10), 10, 16) as u16,
+            g: replicate(field(f.0, 10, 10), 10, 16) as u16,
+            b: replicate(field(f.0, 20, 10), 10, 16) as u16,
+            a: replicate(field(f.0, 30, 2), 2, 16) as u16
+        }
+    }
+}
+
+//
+// Gray to Gray16
+//
+
+impl From<NormalizedGrayscaleColor> for DigitalGrayscale16Color {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        DigitalGrayscale16Color{
+            v: f32_to_u16(f.v)
+        }
+    }
+}
+
+impl From<DigitalGrayscaleColor> for DigitalGrayscale16Color {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        DigitalGrayscale16Color{
+            v: u8_to_u16(f.v)
+        }
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalRGB16Color> for DigitalGrayscale16Color {
+    fn from(f: DigitalRGB16Color) -> Self {
+        DigitalGrayscale16Color::from(NormalizedGrayscaleColor::from(NormalizedRGBColor::from(f)))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalRGBA16Color> for DigitalGrayscale16Color {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        DigitalGrayscale16Color::from(DigitalRGB16Color::from(f))
+    }
+}
+
+//
+// YCbCr to YCbCr16
+//
+
+impl DigitalYCbCr16Color {
+    /// Quantize a normalized color to `bit_depth` bits in `range`
+    ///
+    /// Panics if `bit_depth` is not in the range of 8 to 16.
+    pub fn from_normalized(f: NormalizedYCbCrColor, range: YCbCrRange, bit_depth: u32) -> Self {
+        DigitalYCbCr16Color::from(range.quantize(f, bit_depth))
+    }
+
+    /// Convert a RGB color using the specified `matrix` and quantize it to `bit_depth` bits in `range`
+    ///
+    /// Panics if `bit_depth` is not in the range of 8 to 16.
+    pub fn from_rgb(f: DigitalRGB16Color, matrix: YCbCrMatrix, range: YCbCrRange, bit_depth: u32) -> Self {
+        DigitalYCbCr16Color::from_normalized(
+            NormalizedYCbCrColor::from_rgb(NormalizedRGBColor::from(f), matrix),
+            range,
+            bit_depth
+        )
+    }
+}
+
+impl From<NormalizedYCbCrColor> for DigitalYCbCr16Color {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalYCbCr16Color::from_normalized(f, YCbCrRange::Full, 16)
+    }
+}
+
+impl From<DigitalYCbCrColor> for DigitalYCbCr16Color {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        DigitalYCbCr16Color::from(NormalizedYCbCrColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalRGB16Color> for DigitalYCbCr16Color {
+    fn from(f: DigitalRGB16Color) -> Self {
+        DigitalYCbCr16Color::from(NormalizedYCbCrColor::from(NormalizedRGBColor::from(f)))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl RGBA16Convertible for DigitalRGB16Color {
+    fn convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color> {
+        items.into_iter().map(DigitalRGBA16Color::from).collect()
+    }
+}
+
+impl Grayscale16Convertible for DigitalRGB16Color {
+    fn convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color> {
+        items.into_iter().map(DigitalGrayscale16Color::from).collect()
+    }
+}
+
+impl YCbCr16Convertible for DigitalRGB16Color {
+    fn convert_vec_ycbcr16(items: Vec<Self>) -> Vec<DigitalYCbCr16Color> {
+        items.into_iter().map(DigitalYCbCr16Color::from).collect()
+    }
+}
+
+impl RGB16Convertible for DigitalRGBA16Color {
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color> {
+        items.into_iter().map(DigitalRGB16Color::from).collect()
+    }
+}
+
+impl Grayscale16Convertible for DigitalRGBA16Color {
+    fn convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color> {
+        items.into_iter().map(DigitalGrayscale16Color::from).collect()
+    }
+}
+
+impl RGB16Convertible for DigitalGrayscale16Color {
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color> {
+        items.into_iter().map(DigitalRGB16Color::from).collect()
+    }
+}
+
+impl RGBA16Convertible for DigitalGrayscale16Color {
+    fn convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color> {
+        items.into_iter().map(DigitalRGBA16Color::from).collect()
+    }
+}
+
+impl RGB16Convertible for DigitalYCbCr16Color {
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color> {
+        items.into_iter().map(DigitalRGB16Color::from).collect()
+    }
+}
+
+impl RGBConvertible for DigitalRGB16Color {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBAConvertible for DigitalRGBA16Color {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl GrayscaleConvertible for DigitalGrayscale16Color {
+    fn convert_vec_grayscale(items: Vec<Self>) -> Vec<DigitalGrayscaleColor> {
+        items.into_iter().map(DigitalGrayscaleColor::from).collect()
+    }
+
+    fn convert_iter_grayscale(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalGrayscaleColor>> {
+        Box::new(items.map(DigitalGrayscaleColor::from))
+    }
+}
+
+impl YCbCrConvertible for DigitalYCbCr16Color {
+    fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn u8_u16_scaling() {
+        for v in 0..=255u8 {
+            assert_eq!(v, super::u16_to_u8(super::u8_to_u16(v)));
+        }
+        assert_eq!(65535, super::u8_to_u16(255));
+        assert_eq!(128, super::u16_to_u8(0x8000));
+        assert_eq!(0, super::u16_to_u8(128));
+        assert_eq!(1, super::u16_to_u8(129));
+    }
+
+    #[test]
+    fn rgb_2_rgb16() {
+        let c = DigitalRGBColor{ r: 255, g: 128, b: 0 };
+        let c16 = DigitalRGB16Color::from(c);
+        assert_eq!(DigitalRGB16Color{ r: 65535, g: 32896, b: 0 }, c16);
+        assert_eq!(c, DigitalRGBColor::from(c16));
+
+        let n = NormalizedRGBColor{ r: 1.0, g: 0.5, b: 0.0 };
+        assert_eq!(DigitalRGB16Color{ r: 65535, g: 32768, b: 0 }, DigitalRGB16Color::from(n));
+    }
+
+    #[test]
+    fn rgba16_2_rgba() {
+        let c = DigitalRGBA16Color{ r: 65535, g: 0, b: 257, a: 32896 };
+        assert_eq!(DigitalRGBAColor{ r: 255, g: 0, b: 1, a: 128 }, DigitalRGBAColor::from(c));
+        let n = NormalizedRGBAColor::from(c);
+        assert_eq!(c, DigitalRGBA16Color::from(n));
+    }
+
+    #[test]
+    fn gray16() {
+        let white = DigitalRGB16Color{ r: 65535, g: 65535, b: 65535 };
+        assert_eq!(DigitalGrayscale16Color{ v: 65535 }, DigitalGrayscale16Color::from(white));
+        assert_eq!(DigitalGrayscaleColor{ v: 255 }, DigitalGrayscaleColor::from(DigitalGrayscale16Color{ v: 65535 }));
+    }
+
+    #[test]
+    fn ycbcr16_10_bit() {
+        let black = NormalizedYCbCrColor{ y: 0.0, cb: 0.0, cr: 0.0 };
+        let white = NormalizedYCbCrColor{ y: 1.0, cb: 0.0, cr: 0.0 };
+        assert_eq!(DigitalYCbCr16Color{ y: 64, cb: 512, cr: 512 }, DigitalYCbCr16Color::from_normalized(black, YCbCrRange::Limited, 10));
+        assert_eq!(DigitalYCbCr16Color{ y: 940, cb: 512, cr: 512 }, DigitalYCbCr16Color::from_normalized(white, YCbCrRange::Limited, 10));
+        assert_eq!(DigitalYCbCr16Color{ y: 4095, cb: 2048, cr: 2048 }, DigitalYCbCr16Color::from_normalized(white, YCbCrRange::Full, 12));
+
+        let n = NormalizedYCbCrColor::from_digital16(DigitalYCbCr16Color{ y: 940, cb: 512, cr: 512 }, YCbCrRange::Limited, 10);
+        assert_eq!(white, n);
+    }
+
+    #[test]
+    fn rgb16_ycbcr16_roundtrip() {
+        let rgb = DigitalRGB16Color{ r: 60000, g: 30000, b: 1000 };
+        let ycbcr = DigitalYCbCr16Color::from_rgb(rgb, YCbCrMatrix::BT2020, YCbCrRange::Full, 16);
+        let back = DigitalRGB16Color::from_ycbcr(ycbcr, YCbCrMatrix::BT2020, YCbCrRange::Full, 16);
+        assert!((back.r as i32 - rgb.r as i32).abs() <= 2, "{:?} != {:?}", back, rgb);
+        assert!((back.g as i32 - rgb.g as i32).abs() <= 2, "{:?} != {:?}", back, rgb);
+        assert!((back.b as i32 - rgb.b as i32).abs() <= 2, "{:?} != {:?}", back, rgb);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::*;
+use std::mem::{align_of, size_of, size_of_val};
+use std::slice;
+
+//
+// Casts
+//
+
+pub(crate) fn as_bytes<T: PlainColor>(items: &[T]) -> &[u8] {
+    // SAFETY: `PlainColor` types have no padding, so every byte is initialized
+    unsafe { slice::from_raw_parts(items.as_ptr() as *const u8, size_of_val(items)) }
+}
+
+pub(crate) fn as_bytes_mut<T: PlainColor>(items: &mut [T]) -> &mut [u8] {
+    // SAFETY: `PlainColor` types have no padding and are valid for every bit pattern
+    unsafe { slice::from_raw_parts_mut(items.as_mut_ptr() as *mut u8, size_of_val(items)) }
+}
+
+fn check<T: PlainColor>(bytes: &[u8]) -> Result<usize, ColorConversionError> {
+    if !bytes.len().is_multiple_of(size_of::<T>()) {
+        return Err(ColorConversionError::InvalidBufferLength);
+    }
+
+    if !(bytes.as_ptr() as usize).is_multiple_of(align_of::<T>()) {
+        return Err(ColorConversionError::UnalignedBuffer);
+    }
+
+    Ok(bytes.len() / size_of::<T>())
+}
+
+pub(crate) fn from_bytes<T: PlainColor>(bytes: &[u8]) -> Result<&[T], ColorConversionError> {
+    let len = check::<T>(bytes)?;
+
+    // SAFETY: length and alignment are checked, `PlainColor` types are valid for every bit pattern
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, len) })
+}
+
+pub(crate) fn from_bytes_mut<T: PlainColor>(bytes: &mut [u8]) -> Result<&mut [T], ColorConversionError> {
+    let len = check::<T>(bytes)?;
+
+    // SAFETY: length and alignment are checked, `PlainColor` types are valid for every bit pattern
+    Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, len) })
+}
+
+//
+// Slice conversions
+//
+
+pub(crate) fn convert_slice<S: Copy, D: From<S>>(items: &[S], output: &mut [D]) -> Result<(), ColorConversionError> {
+    if output.len() < items.len() {
+        return Err(ColorConversionError::BufferTooSmall);
+    }
+
+    for (o, i) in output.iter_mut().zip(items) {
+        *o = D::from(*i);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn convert_in_place<S: PlainColor, D: PlainColor + From<S>>(items: &mut [S]) -> Result<&mut [D], ColorConversionError> {
+    if size_of::<S>() != size_of::<D>() {
+        return Err(ColorConversionError::InvalidBufferLength);
+    }
+
+    // validate the alignment before anything is overwritten
+    from_bytes::<D>(as_bytes(items))?;
+
+    let bytes = as_bytes_mut(items);
+    for chunk in bytes.chunks_exact_mut(size_of::<S>()) {
+        let color = D::from(from_bytes::<S>(chunk)?[0]);
+        chunk.copy_from_slice(as_bytes(&[color]));
+    }
+
+    from_bytes_mut(bytes)
+}
+
+//
+// Plain color types
+//
+
+unsafe impl PlainColor for DigitalGrayscaleColor {}
+unsafe impl PlainColor for NormalizedGrayscaleColor {}
+unsafe impl PlainColor for DigitalRGBColor {}
+unsafe impl PlainColor for NormalizedRGBColor {}
+unsafe impl PlainColor for DigitalRGBAColor {}
+unsafe impl PlainColor for NormalizedRGBAColor {}
+unsafe impl PlainColor for DigitalPremultipliedRGBAColor {}
+unsafe impl PlainColor for NormalizedPremultipliedRGBAColor {}
+unsafe impl PlainColor for LinearRGBColor {}
+unsafe impl PlainColor for PackedRGB565Color {}
+unsafe impl PlainColor for PackedRGB555Color {}
+unsafe impl PlainColor for PackedRGBA4444Color {}
+unsafe impl PlainColor for PackedRGBA5551Color {}
+unsafe impl PlainColor for PackedRGB10A2Color {}
+unsafe impl PlainColor for DigitalYCbCrColor {}
+unsafe impl PlainColor for NormalizedYCbCrColor {}
+unsafe impl PlainColor for DigitalRGB16Color {}
+unsafe impl PlainColor for DigitalRGBA16Color {}
+unsafe impl PlainColor for DigitalGrayscale16Color {}
+unsafe impl PlainColor for DigitalYCbCr16Color {}
+unsafe impl PlainColor for CIELabColor {}
+unsafe impl PlainColor for CIEXYZColor {}
+unsafe impl PlainColor for NormalizedRGB<f32> {}
+unsafe impl PlainColor for NormalizedRGB<f64> {}
+unsafe impl PlainColor for NormalizedRGB<F16> {}
+unsafe impl PlainColor for NormalizedRGBA<f32> {}
+unsafe impl PlainColor for NormalizedRGBA<f64> {}
+unsafe impl PlainColor for NormalizedRGBA<F16> {}
+unsafe impl PlainColor for NormalizedGrayscale<f32> {}
+unsafe impl PlainColor for NormalizedGrayscale<f64> {}
+unsafe impl PlainColor for NormalizedGrayscale<F16> {}
+unsafe impl PlainColor for NormalizedYCbCr<f32> {}
+unsafe impl PlainColor for NormalizedYCbCr<f64> {}
+unsafe impl PlainColor for NormalizedYCbCr<F16> {}
+unsafe impl PlainColor for CIEXYZ<f32> {}
+unsafe impl PlainColor for CIEXYZ<f64> {}
+unsafe impl PlainColor for CIEXYZ<F16> {}
+unsafe impl PlainColor for CIELab<f32> {}
+unsafe impl PlainColor for CIELab<f64> {}
+unsafe impl PlainColor for CIELab<F16> {}
+unsafe impl PlainColor for DigitalGrayscaleAlphaColor {}
+unsafe impl PlainColor for NormalizedGrayscaleAlphaColor {}
+unsafe impl PlainColor for DigitalYCbCrAColor {}
+unsafe impl PlainColor for NormalizedYCbCrAColor {}
+unsafe impl PlainColor for CIELabAColor {}
+unsafe impl PlainColor for CIEXYZAColor {}
+unsafe impl PlainColor for CIELChColor {}
+unsafe impl PlainColor for CIELuvColor {}
+unsafe impl PlainColor for CIELChuvColor {}
+unsafe impl PlainColor for OklabColor {}
+unsafe impl PlainColor for OklchColor {}
+unsafe impl PlainColor for DigitalHSLColor {}
+unsafe impl PlainColor for NormalizedHSLColor {}
+unsafe impl PlainColor for DigitalHSVColor {}
+unsafe impl PlainColor for NormalizedHSVColor {}
+unsafe impl PlainColor for DigitalYCgCoColor {}
+unsafe impl PlainColor for NormalizedYCgCoColor {}
+unsafe impl PlainColor for DigitalYCoCgRColor {}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rgb_d_from_bytes() {
+        let bytes = [1u8, 2, 3, 4, 5, 6];
+        let colors = DigitalRGBColor::from_bytes(&bytes).unwrap();
+        assert_eq!(&[DigitalRGBColor{ r: 1, g: 2, b: 3 }, DigitalRGBColor{ r: 4, g: 5, b: 6 }], colors);
+        assert_eq!(&bytes, DigitalRGBColor::as_bytes(colors));
+    }
+
+    #[test]
+    fn rgba_d_from_bytes_mut() {
+        let mut bytes = [0u8; 8];
+        DigitalRGBAColor::from_bytes_mut(&mut bytes).unwrap()[1] = DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 };
+        assert_eq!([0, 0, 0, 0, 1, 2, 3, 4], bytes);
+
+        let mut colors = [DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 }];
+        DigitalRGBAColor::as_bytes_mut(&mut colors)[3] = 255;
+        assert_eq!(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }, colors[0]);
+    }
+
+    #[test]
+    fn rgb_n_from_bytes() {
+        let colors = [NormalizedRGBColor{ r: 1.0, g: 0.5, b: 0.25 }; 2];
+        let bytes = NormalizedRGBColor::as_bytes(&colors);
+        assert_eq!(24, bytes.len());
+        assert_eq!(&colors, NormalizedRGBColor::from_bytes(bytes).unwrap());
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert_eq!(Err(ColorConversionError::InvalidBufferLength), DigitalRGBColor::from_bytes(&[0u8; 4]));
+        assert_eq!(Err(ColorConversionError::InvalidBufferLength), DigitalRGB16Color::from_bytes_mut(&mut [0u8; 7]));
+    }
+
+    #[test]
+    fn unaligned_buffer() {
+        let colors = [NormalizedGrayscaleColor{ v: 0.0 }; 3];
+        let bytes = NormalizedGrayscaleColor::as_bytes(&colors);
+        assert_eq!(Err(ColorConversionError::UnalignedBuffer), NormalizedGrayscaleColor::from_bytes(&bytes[1..9]));
+        assert_eq!(Ok(&colors[1..]), NormalizedGrayscaleColor::from_bytes(&bytes[4..]));
+    }
+
+    #[test]
+    fn generic_from_bytes() {
+        let colors = [NormalizedRGB::<F16>{ r: F16::from_f32(1.0), g: F16::from_f32(0.5), b: F16::from_f32(0.0) }];
+        let bytes = NormalizedRGB::<F16>::as_bytes(&colors);
+        assert_eq!(6, bytes.len());
+        assert_eq!(&colors, NormalizedRGB::<F16>::from_bytes(bytes).unwrap());
+    }
+
+    #[test]
+    fn convert_slice() {
+        let items = [DigitalYCbCrColor{ y: 0, cb: 128, cr: 128 }, DigitalYCbCrColor{ y: 255, cb: 128, cr: 128 }];
+        let mut output = [DigitalRGBColor{ r: 1, g: 1, b: 1 }; 3];
+        DigitalYCbCrColor::convert_slice_rgb(&items, &mut output).unwrap();
+        assert_eq!(DigitalYCbCrColor::convert_vec_rgb(items.to_vec()), output[..2].to_vec());
+        assert_eq!(DigitalRGBColor{ r: 1, g: 1, b: 1 }, output[2]);
+
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), DigitalYCbCrColor::convert_slice_rgb(&items, &mut output[..1]));
+    }
+
+    #[test]
+    fn convert_in_place() {
+        let mut items = [DigitalRGBColor{ r: 255, g: 0, b: 0 }, DigitalRGBColor{ r: 0, g: 0, b: 255 }];
+        let expected: Vec<DigitalYCbCrColor> = items.iter().map(|c| DigitalYCbCrColor::from(*c)).collect();
+        assert_eq!(expected, DigitalRGBColor::convert_in_place_ycbcr(&mut items).unwrap());
+
+        let mut items = [NormalizedRGBColor{ r: 1.0, g: 0.5, b: 0.25 }; 2];
+        let expected = CIEXYZColor::from(items[0]);
+        assert_eq!(&[expected; 2], NormalizedRGBColor::convert_in_place_xyz(&mut items).unwrap());
+    }
+
+    #[test]
+    fn convert_in_place_size_mismatch() {
+        let mut items = [DigitalRGBColor{ r: 255, g: 0, b: 0 }];
+        assert_eq!(Err(ColorConversionError::InvalidBufferLength), DigitalRGBColor::convert_in_place_rgba(&mut items));
+        assert_eq!(DigitalRGBColor{ r: 255, g: 0, b: 0 }, items[0]);
+    }
+}
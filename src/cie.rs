@@ -1,18 +1,29 @@
 use crate::*;
+use crate::lch::from_polar;
+use crate::luv::uv_prime;
+use crate::oklab::oklab_to_lms;
 
 // κ and ε parameters used in conversion between XYZ and La*b*.  See
 // http://www.brucelindbloom.com/LContinuity.html for explanation as to why
 // those are different values than those provided by CIE standard.
-const KAPPA: f32 = 24389.0 / 27.0;
-const EPSILON: f32 = 216.0 / 24389.0;
+pub(crate) const KAPPA: f32 = 24389.0 / 27.0;
+pub(crate) const EPSILON: f32 = 216.0 / 24389.0;
+pub(crate) const KAPPA_F64: f64 = 24389.0 / 27.0;
+pub(crate) const EPSILON_F64: f64 = 216.0 / 24389.0;
+#[allow(clippy::excessive_precision)]
 const CBRT_EPSILON: f32 = 0.20689655172413796;
 
 //
 // CIE to CIE
 //
 
-impl From<CIELabColor> for CIEXYZColor {
-    fn from(f: CIELabColor) -> Self {
+impl CIEXYZColor {
+    /// Convert a Lab color that is relative to the reference white `white`
+    ///
+    /// The resulting XYZ values are relative to the same white point, use `adapt` to
+    /// convert them to another white point.
+    pub fn from_lab(f: CIELabColor, white: WhitePoint) -> Self {
+        let w = white.xyz();
         let fy = (f.l + 16.0) / 116.0;
         let fx = (f.a / 500.0) + fy;
         let fz = fy - (f.b / 200.0);
@@ -33,18 +44,23 @@ impl From<CIELabColor> for CIEXYZColor {
         };
 
         CIEXYZColor{
-            x: xr * 0.95047,
-            y: yr,
-            z: zr * 1.08883
+            x: xr * w.x,
+            y: yr * w.y,
+            z: zr * w.z
         }
     }
 }
 
-impl From<CIEXYZColor> for CIELabColor {
-    fn from(f: CIEXYZColor) -> Self {
-        let x = xyz_to_lab_map(f.x / 0.95047);
-        let y = xyz_to_lab_map(f.y);
-        let z = xyz_to_lab_map(f.z / 1.08883);
+impl CIELabColor {
+    /// Convert a XYZ color to Lab relative to the reference white `white`
+    ///
+    /// The XYZ values have to be relative to the same white point, use `adapt` to
+    /// convert them from another white point.
+    pub fn from_xyz(f: CIEXYZColor, white: WhitePoint) -> Self {
+        let w = white.xyz();
+        let x = xyz_to_lab_map(f.x / w.x);
+        let y = xyz_to_lab_map(f.y / w.y);
+        let z = xyz_to_lab_map(f.z / w.z);
 
         CIELabColor {
             l: (116.0 * y) - 16.0,
@@ -54,6 +70,20 @@ impl From<CIEXYZColor> for CIELabColor {
     }
 }
 
+/// using a `D65` reference white
+impl From<CIELabColor> for CIEXYZColor {
+    fn from(f: CIELabColor) -> Self {
+        CIEXYZColor::from_lab(f, WhitePoint::D65)
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIEXYZColor> for CIELabColor {
+    fn from(f: CIEXYZColor) -> Self {
+        CIELabColor::from_xyz(f, WhitePoint::D65)
+    }
+}
+
 #[inline]
 fn xyz_to_lab_map(c: f32) -> f32 {
     if c > EPSILON {
@@ -63,6 +93,109 @@ fn xyz_to_lab_map(c: f32) -> f32 {
     }
 }
 
+#[inline]
+pub(crate) fn xyz_to_lab_map_f64(c: f64) -> f64 {
+    if c > EPSILON_F64 {
+        c.cbrt()
+    } else {
+        (KAPPA_F64 * c + 16.0) / 116.0
+    }
+}
+
+//
+// LCh and Luv to CIE
+//
+
+impl From<CIELChColor> for CIELabColor {
+    fn from(f: CIELChColor) -> Self {
+        let (a, b) = from_polar(f.c, f.h);
+
+        CIELabColor{
+            l: f.l,
+            a,
+            b
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELChColor> for CIEXYZColor {
+    fn from(f: CIELChColor) -> Self {
+        CIEXYZColor::from(CIELabColor::from(f))
+    }
+}
+
+/// using a `D65` reference white and the [Luv to XYZ conversion](http://www.brucelindbloom.com/index.html?Eqn_Luv_to_XYZ.html)
+impl From<CIELuvColor> for CIEXYZColor {
+    fn from(f: CIELuvColor) -> Self {
+        if f.l <= 0.0 {
+            return CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 };
+        }
+
+        let w = WhitePoint::D65.xyz();
+        let (un, vn) = uv_prime(w);
+        let y = if f.l > KAPPA * EPSILON {
+            ((f.l + 16.0) / 116.0).powi(3)
+        } else {
+            f.l / KAPPA
+        } * w.y;
+        let u = f.u / (13.0 * f.l) + un;
+        let v = f.v / (13.0 * f.l) + vn;
+
+        CIEXYZColor{
+            x: y * 9.0 * u / (4.0 * v),
+            y,
+            z: y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v)
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELuvColor> for CIELabColor {
+    fn from(f: CIELuvColor) -> Self {
+        CIELabColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELChuvColor> for CIEXYZColor {
+    fn from(f: CIELChuvColor) -> Self {
+        CIEXYZColor::from(CIELuvColor::from(f))
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELChuvColor> for CIELabColor {
+    fn from(f: CIELChuvColor) -> Self {
+        CIELabColor::from(CIELuvColor::from(f))
+    }
+}
+
+//
+// Oklab to CIE
+//
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+#[allow(clippy::excessive_precision)]
+impl From<OklabColor> for CIEXYZColor {
+    fn from(f: OklabColor) -> Self {
+        let lms = oklab_to_lms(f);
+
+        CIEXYZColor{
+            x:  1.2270138511 * lms[0] - 0.5577999807 * lms[1] + 0.2812561490 * lms[2],
+            y: -0.0405801784 * lms[0] + 1.1122568696 * lms[1] - 0.0716766787 * lms[2],
+            z: -0.0763812845 * lms[0] - 0.4214819784 * lms[1] + 1.5861632204 * lms[2]
+        }
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<OklchColor> for CIEXYZColor {
+    fn from(f: OklchColor) -> Self {
+        CIEXYZColor::from(OklabColor::from(f))
+    }
+}
+
 //
 // Gray to CIE
 //
@@ -104,24 +237,28 @@ impl From<NormalizedGrayscaleColor> for CIEXYZColor {
 //
 
 /// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
-impl From<DigitalRGBColor> for CIEXYZColor {
-    fn from(f: DigitalRGBColor) -> Self {
-        let r = rgb_to_xyz_map(f.r);
-        let g = rgb_to_xyz_map(f.g);
-        let b = rgb_to_xyz_map(f.b);
-
+#[allow(clippy::excessive_precision)]
+impl From<LinearRGBColor> for CIEXYZColor {
+    fn from(f: LinearRGBColor) -> Self {
         CIEXYZColor{
-            x: r * 0.4124564390896921 + g * 0.357576077643909 + b * 0.18043748326639894,
-            y: r * 0.21267285140562248 + g * 0.715152155287818 + b * 0.07217499330655958,
-            z: r * 0.019333895582329317 + g * 0.119192025881303 + b * 0.9503040785363677
+            x: f.r * 0.4124564390896921 + f.g * 0.357576077643909 + f.b * 0.18043748326639894,
+            y: f.r * 0.21267285140562248 + f.g * 0.715152155287818 + f.b * 0.07217499330655958,
+            z: f.r * 0.019333895582329317 + f.g * 0.119192025881303 + f.b * 0.9503040785363677
         }
     }
 }
 
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<DigitalRGBColor> for CIEXYZColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        CIEXYZColor::from(LinearRGBColor::from(f))
+    }
+}
+
 /// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
 impl From<NormalizedRGBColor> for CIEXYZColor {
     fn from(f: NormalizedRGBColor) -> Self {
-        CIEXYZColor::from(DigitalRGBColor::from(f))
+        CIEXYZColor::from(LinearRGBColor::from(f))
     }
 }
 
@@ -135,7 +272,7 @@ impl From<DigitalRGBAColor> for CIEXYZColor {
 /// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
 impl From<NormalizedRGBAColor> for CIEXYZColor {
     fn from(f: NormalizedRGBAColor) -> Self {
-        CIEXYZColor::from(DigitalRGBColor::from(f))
+        CIEXYZColor::from(LinearRGBColor::from(f))
     }
 }
 
@@ -167,18 +304,6 @@ impl From<NormalizedRGBAColor> for CIELabColor {
     }
 }
 
-#[inline]
-fn rgb_to_xyz_map(c: u8) -> f32 {
-    if c > 10 {
-        const A: f32 = 0.055 * 255.0;
-        const D: f32 = 1.055 * 255.0;
-        ((c as f32 + A) / D).powf(2.4)
-    } else {
-        const D: f32 = 12.92 * 255.0;
-        c as f32 / D
-    }
-}
-
 //
 // YCbCr to CIE
 //
@@ -215,50 +340,366 @@ impl From<DigitalYCbCrColor> for CIELabColor {
     }
 }
 
+//
+// HSL to CIE
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<NormalizedHSLColor> for CIEXYZColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        CIEXYZColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<DigitalHSLColor> for CIEXYZColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        CIEXYZColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<NormalizedHSLColor> for CIELabColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        CIELabColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<DigitalHSLColor> for CIELabColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        CIELabColor::from(CIEXYZColor::from(f))
+    }
+}
+
+//
+// HSV to CIE
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<NormalizedHSVColor> for CIEXYZColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        CIEXYZColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<DigitalHSVColor> for CIEXYZColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        CIEXYZColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<NormalizedHSVColor> for CIELabColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        CIELabColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion from RGB
+impl From<DigitalHSVColor> for CIELabColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        CIELabColor::from(CIEXYZColor::from(f))
+    }
+}
+
+//
+// Batch conversions
+//
+
+impl CIEXYZColor {
+    /// Convert a vector of XYZ colors into a vector of `DigitalRGBColor` values, evaluating the
+    /// sRGB transfer function with `precision`
+    pub fn convert_vec_rgb_with(items: Vec<Self>, precision: TransferPrecision) -> Vec<DigitalRGBColor> {
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        simd::xyz_to_rgb(&items, &mut output, precision);
+        output
+    }
+
+    /// Convert a slice of XYZ colors into a buffer of `DigitalRGBColor` values, evaluating the
+    /// sRGB transfer function with `precision`
+    pub fn convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], precision: TransferPrecision) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::xyz_to_rgb(items, output, precision);
+        Ok(())
+    }
+
+    /// Convert a vector of XYZ colors into a vector of `DigitalRGBColor` values on multiple threads,
+    /// evaluating the sRGB transfer function with `precision`
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_vec_rgb_with(items: Vec<Self>, precision: TransferPrecision) -> Vec<DigitalRGBColor> {
+        parallel::convert_vec(items, |i, o| Self::convert_slice_rgb_with(i, o, precision))
+    }
+
+    /// Convert a slice of XYZ colors into a buffer of `DigitalRGBColor` values on multiple threads,
+    /// evaluating the sRGB transfer function with `precision`
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], precision: TransferPrecision) -> Result<(), ColorConversionError> {
+        parallel::convert_slice(items, output, |i, o| Self::convert_slice_rgb_with(i, o, precision))
+    }
+}
+
+impl CIELabColor {
+    /// Convert a vector of Lab colors into a vector of `DigitalRGBColor` values, evaluating the
+    /// sRGB transfer function with `precision`
+    pub fn convert_vec_rgb_with(items: Vec<Self>, precision: TransferPrecision) -> Vec<DigitalRGBColor> {
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        simd::lab_to_rgb(&items, &mut output, precision);
+        output
+    }
+
+    /// Convert a slice of Lab colors into a buffer of `DigitalRGBColor` values, evaluating the
+    /// sRGB transfer function with `precision`
+    pub fn convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], precision: TransferPrecision) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::lab_to_rgb(items, output, precision);
+        Ok(())
+    }
+
+    /// Convert a vector of Lab colors into a vector of `DigitalRGBColor` values on multiple threads,
+    /// evaluating the sRGB transfer function with `precision`
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_vec_rgb_with(items: Vec<Self>, precision: TransferPrecision) -> Vec<DigitalRGBColor> {
+        parallel::convert_vec(items, |i, o| Self::convert_slice_rgb_with(i, o, precision))
+    }
+
+    /// Convert a slice of Lab colors into a buffer of `DigitalRGBColor` values on multiple threads,
+    /// evaluating the sRGB transfer function with `precision`
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], precision: TransferPrecision) -> Result<(), ColorConversionError> {
+        parallel::convert_slice(items, output, |i, o| Self::convert_slice_rgb_with(i, o, precision))
+    }
+}
+
 // 
 // Color conversion traits
 //
 
 impl YCbCrConvertible for CIELabColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl YCbCrConvertible for CIEXYZColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl RGBConvertible for CIELabColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        simd::lab_to_rgb(&items, &mut output, TransferPrecision::Exact);
+        output
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+
+    fn convert_slice_rgb(items: &[Self], output: &mut [DigitalRGBColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::lab_to_rgb(items, output, TransferPrecision::Exact);
+        Ok(())
     }
 }
 
 impl RGBConvertible for CIEXYZColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        simd::xyz_to_rgb(&items, &mut output, TransferPrecision::Exact);
+        output
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+
+    fn convert_slice_rgb(items: &[Self], output: &mut [DigitalRGBColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::xyz_to_rgb(items, output, TransferPrecision::Exact);
+        Ok(())
     }
 }
 
 impl RGBAConvertible for CIELabColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl RGBAConvertible for CIEXYZColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl HSLConvertible for CIEXYZColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for CIEXYZColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for CIELabColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for CIELabColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl CIELChConvertible for CIELabColor {
+    fn convert_vec_lch(items: Vec<Self>) -> Vec<CIELChColor> {
+        items.into_iter().map(CIELChColor::from).collect()
+    }
+}
+
+impl CIELuvConvertible for CIELabColor {
+    fn convert_vec_luv(items: Vec<Self>) -> Vec<CIELuvColor> {
+        items.into_iter().map(CIELuvColor::from).collect()
+    }
+}
+
+impl CIELChuvConvertible for CIELabColor {
+    fn convert_vec_lchuv(items: Vec<Self>) -> Vec<CIELChuvColor> {
+        items.into_iter().map(CIELChuvColor::from).collect()
+    }
+}
+
+impl CIELChConvertible for CIEXYZColor {
+    fn convert_vec_lch(items: Vec<Self>) -> Vec<CIELChColor> {
+        items.into_iter().map(CIELChColor::from).collect()
+    }
+}
+
+impl CIELuvConvertible for CIEXYZColor {
+    fn convert_vec_luv(items: Vec<Self>) -> Vec<CIELuvColor> {
+        items.into_iter().map(CIELuvColor::from).collect()
+    }
+}
+
+impl CIELChuvConvertible for CIEXYZColor {
+    fn convert_vec_lchuv(items: Vec<Self>) -> Vec<CIELChuvColor> {
+        items.into_iter().map(CIELChuvColor::from).collect()
+    }
+}
+
+impl OklabConvertible for CIEXYZColor {
+    fn convert_vec_oklab(items: Vec<Self>) -> Vec<OklabColor> {
+        items.into_iter().map(OklabColor::from).collect()
+    }
+}
+
+impl OklchConvertible for CIEXYZColor {
+    fn convert_vec_oklch(items: Vec<Self>) -> Vec<OklchColor> {
+        items.into_iter().map(OklchColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn lab_d50_white() {
+        let white = CIELabColor::from_xyz(WhitePoint::D50.xyz(), WhitePoint::D50);
+        assert!((white.l - 100.0).abs() < 1e-4);
+        assert!(white.a.abs() < 1e-4);
+        assert!(white.b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn lab_d50_from_rgb() {
+        // sRGB red in D50 Lab (Bradford adapted), see http://www.brucelindbloom.com/index.html?ColorCalculator.html
+        let xyz = CIEXYZColor::from(DigitalRGBColor{ r: 255, g: 0, b: 0 })
+            .adapt(WhitePoint::D65, WhitePoint::D50, ChromaticAdaptation::Bradford);
+        let lab = CIELabColor::from_xyz(xyz, WhitePoint::D50);
+        assert!((lab.l - 54.29).abs() < 0.05, "{:?}", lab);
+        assert!((lab.a - 80.80).abs() < 0.05, "{:?}", lab);
+        assert!((lab.b - 69.89).abs() < 0.05, "{:?}", lab);
+    }
+
+    #[test]
+    fn lab_2_xyz_roundtrip() {
+        let lab = CIELabColor{ l: 50.0, a: 20.0, b: -30.0 };
+        let back = CIELabColor::from_xyz(CIEXYZColor::from_lab(lab, WhitePoint::A), WhitePoint::A);
+        assert!((lab.l - back.l).abs() < 1e-3);
+        assert!((lab.a - back.a).abs() < 1e-3);
+        assert!((lab.b - back.b).abs() < 1e-3);
+    }
+
+    #[test]
+    fn xyz_and_lab_2_rgb_d_precision() {
+        let items: Vec<CIEXYZColor> = (0..=1000).map(|i| {
+            let v = i as f32 / 1000.0;
+            CIEXYZColor{ x: v * 0.95047, y: v, z: (1.0 - v) * 1.08883 }
+        }).collect();
+        let lab: Vec<CIELabColor> = items.iter().map(|c| CIELabColor::from(*c)).collect();
+
+        let exact: Vec<DigitalRGBColor> = items.iter().map(|c| DigitalRGBColor::from(*c)).collect();
+        assert_eq!(exact, CIEXYZColor::convert_vec_rgb_with(items.clone(), TransferPrecision::Exact));
+
+        let exact_lab: Vec<DigitalRGBColor> = lab.iter().map(|c| DigitalRGBColor::from(*c)).collect();
+        assert_eq!(exact_lab, CIELabColor::convert_vec_rgb_with(lab.clone(), TransferPrecision::Exact));
+
+        assert_eq!(exact, CIEXYZColor::convert_vec_rgb(items.clone()));
+        assert_eq!(exact_lab, CIELabColor::convert_vec_rgb(lab.clone()));
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        CIEXYZColor::convert_slice_rgb(&items, &mut output).unwrap();
+        assert_eq!(exact, output);
+        CIELabColor::convert_slice_rgb(&lab, &mut output).unwrap();
+        assert_eq!(exact_lab, output);
+
+        let fast = CIEXYZColor::convert_vec_rgb_with(items, TransferPrecision::Fast);
+        let fast_lab = CIELabColor::convert_vec_rgb_with(lab, TransferPrecision::Fast);
+        for (e, f) in exact.iter().zip(&fast).chain(exact_lab.iter().zip(&fast_lab)) {
+            assert!((e.r as i32 - f.r as i32).abs() <= 1, "{:?} != {:?}", e, f);
+            assert!((e.g as i32 - f.g as i32).abs() <= 1, "{:?} != {:?}", e, f);
+            assert!((e.b as i32 - f.b as i32).abs() <= 1, "{:?} != {:?}", e, f);
+        }
     }
 }
@@ -0,0 +1,270 @@
+use crate::*;
+use crate::matrix::{self, Matrix3};
+
+//
+// Transfer functions
+//
+
+impl TransferFunction {
+    /// Decode a gamma encoded component value to linear light
+    pub fn decode(self, v: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => v,
+            TransferFunction::SRGB => srgb_to_linear(v),
+            TransferFunction::Gamma(gamma) => v.abs().powf(gamma).copysign(v),
+            TransferFunction::Rec709 => if v < 0.081 {
+                v / 4.5
+            } else {
+                ((v + 0.099) / 1.099).powf(1.0 / 0.45)
+            },
+            TransferFunction::ProPhoto => if v < 16.0 / 512.0 {
+                v / 16.0
+            } else {
+                v.powf(1.8)
+            }
+        }
+    }
+
+    /// Encode a linear light component value
+    pub fn encode(self, v: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => v,
+            TransferFunction::SRGB => linear_to_srgb(v),
+            TransferFunction::Gamma(gamma) => v.abs().powf(1.0 / gamma).copysign(v),
+            TransferFunction::Rec709 => if v < 0.018 {
+                4.5 * v
+            } else {
+                1.099 * v.powf(0.45) - 0.099
+            },
+            TransferFunction::ProPhoto => if v < 1.0 / 512.0 {
+                16.0 * v
+            } else {
+                v.powf(1.0 / 1.8)
+            }
+        }
+    }
+}
+
+//
+// Color spaces
+//
+
+impl RGBColorSpace {
+    /// sRGB (IEC 61966-2-1), the color space of all other RGB types in this crate
+    pub const SRGB: RGBColorSpace = RGBColorSpace{
+        red: (0.64, 0.33),
+        green: (0.30, 0.60),
+        blue: (0.15, 0.06),
+        white: WhitePoint::D65,
+        transfer: TransferFunction::SRGB
+    };
+
+    /// Display P3 (DCI-P3 primaries with `D65` white and the sRGB curve)
+    pub const DISPLAY_P3: RGBColorSpace = RGBColorSpace{
+        red: (0.680, 0.320),
+        green: (0.265, 0.690),
+        blue: (0.150, 0.060),
+        white: WhitePoint::D65,
+        transfer: TransferFunction::SRGB
+    };
+
+    /// Adobe RGB (1998)
+    pub const ADOBE_RGB: RGBColorSpace = RGBColorSpace{
+        red: (0.64, 0.33),
+        green: (0.21, 0.71),
+        blue: (0.15, 0.06),
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Gamma(563.0 / 256.0)
+    };
+
+    /// ITU-R BT.2020 (UHDTV)
+    pub const REC2020: RGBColorSpace = RGBColorSpace{
+        red: (0.708, 0.292),
+        green: (0.170, 0.797),
+        blue: (0.131, 0.046),
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Rec709
+    };
+
+    /// ProPhoto RGB (ROMM RGB)
+    pub const PROPHOTO: RGBColorSpace = RGBColorSpace{
+        red: (0.7347, 0.2653),
+        green: (0.1596, 0.8404),
+        blue: (0.0366, 0.0001),
+        white: WhitePoint::D50,
+        transfer: TransferFunction::ProPhoto
+    };
+
+    /// Matrix that converts linear RGB values of this color space to `D65` relative XYZ values
+    ///
+    /// using [RGB/XYZ matrix calculation](http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html)
+    pub fn xyz_matrix(&self) -> [[f32; 3]; 3] {
+        let m = self.primaries_matrix();
+        if self.white == WhitePoint::D65 {
+            m
+        } else {
+            let adaptation = ChromaticAdaptation::Bradford.matrix(self.white, WhitePoint::D65);
+            matrix::mul(&adaptation, &m)
+        }
+    }
+
+    /// Matrix that converts `D65` relative XYZ values to linear RGB values of this color space
+    pub fn rgb_matrix(&self) -> [[f32; 3]; 3] {
+        matrix::invert(&self.xyz_matrix())
+    }
+
+    /// Convert a gamma encoded color of this color space to XYZ
+    pub fn to_xyz(&self, f: NormalizedRGBColor) -> CIEXYZColor {
+        to_xyz_with_matrix(&self.xyz_matrix(), self.transfer, f)
+    }
+
+    /// Convert a XYZ color to a gamma encoded color of this color space
+    ///
+    /// The result is not clipped, colors outside of the gamut have components
+    /// smaller than `0.0` or bigger than `1.0`.
+    pub fn from_xyz(&self, f: CIEXYZColor) -> NormalizedRGBColor {
+        from_xyz_with_matrix(&self.rgb_matrix(), self.transfer, f)
+    }
+
+    /// Convert a slice of gamma encoded colors of this color space to XYZ
+    pub fn to_xyz_vec(&self, items: &[NormalizedRGBColor]) -> Vec<CIEXYZColor> {
+        let m = self.xyz_matrix();
+        items.iter().map(|x| to_xyz_with_matrix(&m, self.transfer, *x)).collect()
+    }
+
+    /// Convert a slice of XYZ colors to gamma encoded colors of this color space
+    pub fn from_xyz_vec(&self, items: &[CIEXYZColor]) -> Vec<NormalizedRGBColor> {
+        let m = self.rgb_matrix();
+        items.iter().map(|x| from_xyz_with_matrix(&m, self.transfer, *x)).collect()
+    }
+
+    /// Matrix that converts linear RGB values to XYZ values relative to the white point of this color space
+    fn primaries_matrix(&self) -> Matrix3 {
+        let column = |(x, y): (f32, f32)| [x / y, 1.0, (1.0 - x - y) / y];
+        let r = column(self.red);
+        let g = column(self.green);
+        let b = column(self.blue);
+        let primaries = [
+            [r[0], g[0], b[0]],
+            [r[1], g[1], b[1]],
+            [r[2], g[2], b[2]]
+        ];
+
+        let white = self.white.xyz();
+        let s = matrix::mul_vec(&matrix::invert(&primaries), [white.x, white.y, white.z]);
+
+        matrix::mul(&primaries, &matrix::diagonal(s))
+    }
+}
+
+impl Default for RGBColorSpace {
+    fn default() -> Self {
+        RGBColorSpace::SRGB
+    }
+}
+
+#[inline]
+fn to_xyz_with_matrix(m: &Matrix3, transfer: TransferFunction, f: NormalizedRGBColor) -> CIEXYZColor {
+    let v = matrix::mul_vec(m, [transfer.decode(f.r), transfer.decode(f.g), transfer.decode(f.b)]);
+
+    CIEXYZColor{
+        x: v[0],
+        y: v[1],
+        z: v[2]
+    }
+}
+
+#[inline]
+fn from_xyz_with_matrix(m: &Matrix3, transfer: TransferFunction, f: CIEXYZColor) -> NormalizedRGBColor {
+    let v = matrix::mul_vec(m, [f.x, f.y, f.z]);
+
+    NormalizedRGBColor{
+        r: transfer.encode(v[0]),
+        g: transfer.encode(v[1]),
+        b: transfer.encode(v[2])
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn srgb_matrix() {
+        let m = RGBColorSpace::SRGB.xyz_matrix();
+        let expected = [
+            [0.4124564390896921, 0.357576077643909, 0.18043748326639894],
+            [0.21267285140562248, 0.715152155287818, 0.07217499330655958],
+            [0.019333895582329317, 0.119192025881303, 0.9503040785363677]
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((m[i][j] - expected[i][j]).abs() < 1e-4, "{:?} != {:?}", m, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn srgb_equals_from_impl() {
+        let rgb = DigitalRGBColor{ r: 200, g: 30, b: 90 };
+        let a = CIEXYZColor::from(rgb);
+        let b = RGBColorSpace::SRGB.to_xyz(NormalizedRGBColor::from(rgb));
+        assert!((a.x - b.x).abs() < 1e-4);
+        assert!((a.y - b.y).abs() < 1e-4);
+        assert!((a.z - b.z).abs() < 1e-4);
+    }
+
+    #[test]
+    fn white_maps_to_d65() {
+        let white = NormalizedRGBColor{ r: 1.0, g: 1.0, b: 1.0 };
+        let spaces = [
+            RGBColorSpace::SRGB,
+            RGBColorSpace::DISPLAY_P3,
+            RGBColorSpace::ADOBE_RGB,
+            RGBColorSpace::REC2020,
+            RGBColorSpace::PROPHOTO
+        ];
+        for space in spaces.iter() {
+            let xyz = space.to_xyz(white);
+            let d65 = WhitePoint::D65.xyz();
+            assert!((xyz.x - d65.x).abs() < 1e-4, "{:?}", xyz);
+            assert!((xyz.y - d65.y).abs() < 1e-4, "{:?}", xyz);
+            assert!((xyz.z - d65.z).abs() < 1e-4, "{:?}", xyz);
+        }
+    }
+
+    #[test]
+    fn p3_red_is_out_of_srgb_gamut() {
+        let red = NormalizedRGBColor{ r: 1.0, g: 0.0, b: 0.0 };
+        let xyz = RGBColorSpace::DISPLAY_P3.to_xyz(red);
+        let srgb = RGBColorSpace::SRGB.from_xyz(xyz);
+        assert!(srgb.r > 1.0);
+        assert!(srgb.g < 0.0);
+
+        let back = RGBColorSpace::DISPLAY_P3.from_xyz(xyz);
+        assert!((back.r - 1.0).abs() < 1e-4);
+        assert!(back.g.abs() < 1e-4);
+        assert!(back.b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn transfer_function_roundtrip() {
+        let functions = [
+            TransferFunction::Linear,
+            TransferFunction::SRGB,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Rec709,
+            TransferFunction::ProPhoto
+        ];
+        for function in functions.iter() {
+            for v in [0.0, 0.001, 0.05, 0.5, 1.0].iter() {
+                assert!((function.decode(function.encode(*v)) - v).abs() < 1e-5, "{:?}: {}", function, v);
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::*;
+
+/// IEEE 754 half precision floating point number (binary16)
+///
+/// This is a storage type only, arithmetic has to be done after converting to `f32`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct F16(u16);
+
+impl F16 {
+    /// Create a half-float from its bit representation
+    pub const fn from_bits(bits: u16) -> Self {
+        F16(bits)
+    }
+
+    /// Bit representation of the half-float
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Convert a `f32` value, rounding to the nearest representable value (ties to even)
+    ///
+    /// Values that are too large for a half-float become infinite.
+    pub fn from_f32(v: f32) -> Self {
+        let x = v.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exponent = ((x >> 23) & 0xff) as i32;
+        let mantissa = x & 0x007f_ffff;
+
+        if exponent == 0xff {
+            // infinity keeps an empty mantissa, NaN stays a (quiet) NaN
+            let nan = if mantissa != 0 { 0x0200 } else { 0 };
+            return F16(sign | 0x7c00 | nan);
+        }
+
+        let e = exponent - 127 + 15;
+        if e >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+
+        if e <= 0 {
+            // subnormal half-float or zero
+            if e < -10 {
+                return F16(sign);
+            }
+            let mantissa = mantissa | 0x0080_0000;
+            let shift = (14 - e) as u32;
+            let half = mantissa >> shift;
+            return F16(sign | round_ties_even(half as u64, mantissa as u64, shift) as u16);
+        }
+
+        let half = ((e as u32) << 10) | (mantissa >> 13);
+        // a carry of the rounding correctly overflows into the exponent
+        F16(sign | round_ties_even(half as u64, mantissa as u64, 13) as u16)
+    }
+
+    /// Convert a `f64` value, rounding to the nearest representable value (ties to even)
+    ///
+    /// Values that are too large for a half-float become infinite.
+    pub fn from_f64(v: f64) -> Self {
+        let x = v.to_bits();
+        let sign = ((x >> 48) & 0x8000) as u16;
+        let exponent = ((x >> 52) & 0x7ff) as i32;
+        let mantissa = x & 0x000f_ffff_ffff_ffff;
+
+        if exponent == 0x7ff {
+            let nan = if mantissa != 0 { 0x0200 } else { 0 };
+            return F16(sign | 0x7c00 | nan);
+        }
+
+        let e = exponent - 1023 + 15;
+        if e >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+
+        if e <= 0 {
+            if e < -10 {
+                return F16(sign);
+            }
+            let mantissa = mantissa | 0x0010_0000_0000_0000;
+            let shift = (43 - e) as u32;
+            let half = mantissa >> shift;
+            return F16(sign | round_ties_even(half, mantissa, shift) as u16);
+        }
+
+        let half = ((e as u64) << 10) | (mantissa >> 42);
+        F16(sign | round_ties_even(half, mantissa, 42) as u16)
+    }
+
+    /// Convert to `f32`, this is exact
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exponent = ((self.0 >> 10) & 0x1f) as u32;
+        let mantissa = (self.0 & 0x03ff) as u32;
+
+        match exponent {
+            0 => {
+                let v = mantissa as f32 / 16_777_216.0;
+                if sign != 0 { -v } else { v }
+            },
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13))
+        }
+    }
+}
+
+/// Round `truncated` (which is `value >> shift`) to nearest, ties to even
+#[inline]
+fn round_ties_even(truncated: u64, value: u64, shift: u32) -> u64 {
+    let remainder = value & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+impl From<f32> for F16 {
+    fn from(v: f32) -> Self {
+        F16::from_f32(v)
+    }
+}
+
+impl From<F16> for f32 {
+    fn from(v: F16) -> Self {
+        v.to_f32()
+    }
+}
+
+impl From<F16> for f64 {
+    fn from(v: F16) -> Self {
+        v.to_f32() as f64
+    }
+}
+
+//
+// Component types
+//
+
+impl Component for f32 {
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Component for f64 {
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Component for F16 {
+    fn from_f64(v: f64) -> Self {
+        F16::from_f64(v)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[rustfmt::skip]
+    static F16_VALUES: [(f32, u16); 12] = [
+        (0.0,           0x0000),
+        (-0.0,          0x8000),
+        (1.0,           0x3c00),
+        (-2.0,          0xc000),
+        (0.5,           0x3800),
+        (65504.0,       0x7bff),
+        (65536.0,       0x7c00),
+        (f32::INFINITY, 0x7c00),
+        (0.333333,      0x3555),
+        (6.1035156e-5,  0x0400),
+        (5.9604645e-8,  0x0001),
+        (1.0009766,     0x3c01),
+    ];
+
+    #[test]
+    fn f32_2_f16() {
+        for test in F16_VALUES.iter() {
+            assert_eq!(test.1, F16::from_f32(test.0).to_bits(), "{}", test.0);
+        }
+    }
+
+    #[test]
+    fn f16_2_f32_roundtrip() {
+        for bits in 0..0x7c00u16 {
+            let h = F16::from_bits(bits);
+            assert_eq!(h, F16::from_f32(h.to_f32()), "{:#x}", bits);
+            let h = F16::from_bits(bits | 0x8000);
+            assert_eq!(h, F16::from_f32(h.to_f32()), "{:#x}", bits);
+        }
+    }
+
+    #[test]
+    fn f16_rounding() {
+        // halfway between 1.0 and the next value rounds to even
+        assert_eq!(0x3c00, F16::from_f32(1.000_488_3).to_bits());
+        assert_eq!(0x3c02, F16::from_f32(1.001_464_8).to_bits());
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+        assert_eq!(0, F16::from_f32(1e-10).to_bits());
+    }
+
+    #[test]
+    fn f64_2_f16() {
+        for test in F16_VALUES.iter() {
+            assert_eq!(test.1, F16::from_f64(test.0 as f64).to_bits(), "{}", test.0);
+        }
+        for bits in 0..0x7c00u16 {
+            let h = F16::from_bits(bits);
+            assert_eq!(h, F16::from_f64(h.to_f32() as f64), "{:#x}", bits);
+        }
+
+        // rounds to exactly halfway between 1.0 and the next value as f32, which would round down to even
+        let v = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+        assert_eq!(0x3c00, F16::from_f32(v as f32).to_bits());
+        assert_eq!(0x3c01, F16::from_f64(v).to_bits());
+        assert_eq!(0x3c01, <F16 as Component>::from_f64(v).to_bits());
+        assert_eq!(0x8001, F16::from_f64(-5.9604645e-8).to_bits());
+        assert!(F16::from_f64(f64::NAN).to_f32().is_nan());
+    }
+}
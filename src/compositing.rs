@@ -0,0 +1,366 @@
+use crate::*;
+
+//
+// Compositing of straight alpha colors
+//
+
+impl NormalizedRGBAColor {
+
+    /// Composite this color (the source) onto `backdrop` with a Porter-Duff operator
+    pub fn composite(self, backdrop: NormalizedRGBAColor, op: CompositeOperator) -> NormalizedRGBAColor {
+        NormalizedRGBAColor::from(composite(
+            NormalizedPremultipliedRGBAColor::from(self),
+            NormalizedPremultipliedRGBAColor::from(backdrop),
+            op
+        ))
+    }
+
+    /// Blend this color (the source) with `backdrop` and composite the result with
+    /// the `SourceOver` operator
+    pub fn blend(self, backdrop: NormalizedRGBAColor, mode: BlendMode) -> NormalizedRGBAColor {
+        NormalizedRGBAColor::from(blend(
+            NormalizedPremultipliedRGBAColor::from(self),
+            NormalizedPremultipliedRGBAColor::from(backdrop),
+            mode
+        ))
+    }
+
+    /// Composite all `source` colors onto the `backdrop` colors at the same index,
+    /// the result is written to `backdrop`
+    pub fn composite_slice(source: &[NormalizedRGBAColor], backdrop: &mut [NormalizedRGBAColor], op: CompositeOperator) -> Result<(), ColorConversionError> {
+        if backdrop.len() < source.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        for (s, b) in source.iter().zip(backdrop.iter_mut()) {
+            *b = s.composite(*b, op);
+        }
+        Ok(())
+    }
+
+    /// Blend all `source` colors with the `backdrop` colors at the same index,
+    /// the result is written to `backdrop`
+    pub fn blend_slice(source: &[NormalizedRGBAColor], backdrop: &mut [NormalizedRGBAColor], mode: BlendMode) -> Result<(), ColorConversionError> {
+        if backdrop.len() < source.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        for (s, b) in source.iter().zip(backdrop.iter_mut()) {
+            *b = s.blend(*b, mode);
+        }
+        Ok(())
+    }
+}
+
+//
+// Compositing of premultiplied colors
+//
+
+impl NormalizedPremultipliedRGBAColor {
+
+    /// Composite this color (the source) onto `backdrop` with a Porter-Duff operator
+    pub fn composite(self, backdrop: NormalizedPremultipliedRGBAColor, op: CompositeOperator) -> NormalizedPremultipliedRGBAColor {
+        composite(self, backdrop, op)
+    }
+
+    /// Blend this color (the source) with `backdrop` and composite the result with
+    /// the `SourceOver` operator
+    pub fn blend(self, backdrop: NormalizedPremultipliedRGBAColor, mode: BlendMode) -> NormalizedPremultipliedRGBAColor {
+        blend(self, backdrop, mode)
+    }
+
+    /// Composite all `source` colors onto the `backdrop` colors at the same index,
+    /// the result is written to `backdrop`
+    pub fn composite_slice(source: &[NormalizedPremultipliedRGBAColor], backdrop: &mut [NormalizedPremultipliedRGBAColor], op: CompositeOperator) -> Result<(), ColorConversionError> {
+        if backdrop.len() < source.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        for (s, b) in source.iter().zip(backdrop.iter_mut()) {
+            *b = composite(*s, *b, op);
+        }
+        Ok(())
+    }
+
+    /// Blend all `source` colors with the `backdrop` colors at the same index,
+    /// the result is written to `backdrop`
+    pub fn blend_slice(source: &[NormalizedPremultipliedRGBAColor], backdrop: &mut [NormalizedPremultipliedRGBAColor], mode: BlendMode) -> Result<(), ColorConversionError> {
+        if backdrop.len() < source.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        for (s, b) in source.iter().zip(backdrop.iter_mut()) {
+            *b = blend(*s, *b, mode);
+        }
+        Ok(())
+    }
+}
+
+/// using [Porter-Duff compositing](https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators)
+fn composite(s: NormalizedPremultipliedRGBAColor, b: NormalizedPremultipliedRGBAColor, op: CompositeOperator) -> NormalizedPremultipliedRGBAColor {
+    let (fa, fb) = match op {
+        CompositeOperator::Clear => (0.0, 0.0),
+        CompositeOperator::Source => (1.0, 0.0),
+        CompositeOperator::Destination => (0.0, 1.0),
+        CompositeOperator::SourceOver => (1.0, 1.0 - s.a),
+        CompositeOperator::DestinationOver => (1.0 - b.a, 1.0),
+        CompositeOperator::SourceIn => (b.a, 0.0),
+        CompositeOperator::DestinationIn => (0.0, s.a),
+        CompositeOperator::SourceOut => (1.0 - b.a, 0.0),
+        CompositeOperator::DestinationOut => (0.0, 1.0 - s.a),
+        CompositeOperator::SourceAtop => (b.a, 1.0 - s.a),
+        CompositeOperator::DestinationAtop => (1.0 - b.a, s.a),
+        CompositeOperator::Xor => (1.0 - b.a, 1.0 - s.a),
+        CompositeOperator::Lighter => {
+            return NormalizedPremultipliedRGBAColor{
+                r: (s.r + b.r).min(1.0),
+                g: (s.g + b.g).min(1.0),
+                b: (s.b + b.b).min(1.0),
+                a: (s.a + b.a).min(1.0)
+            };
+        }
+    };
+
+    NormalizedPremultipliedRGBAColor{
+        r: s.r * fa + b.r * fb,
+        g: s.g * fa + b.g * fb,
+        b: s.b * fa + b.b * fb,
+        a: s.a * fa + b.a * fb
+    }
+}
+
+/// using [W3C blending](https://www.w3.org/TR/compositing-1/#blending)
+fn blend(s: NormalizedPremultipliedRGBAColor, b: NormalizedPremultipliedRGBAColor, mode: BlendMode) -> NormalizedPremultipliedRGBAColor {
+    let cs = unpremultiply(s);
+    let cb = unpremultiply(b);
+
+    let mixed = match mode {
+        BlendMode::Normal => cs,
+        BlendMode::Multiply => separable(cb, cs, |cb, cs| cb * cs),
+        BlendMode::Screen => separable(cb, cs, screen),
+        BlendMode::Overlay => separable(cb, cs, |cb, cs| hard_light(cs, cb)),
+        BlendMode::Darken => separable(cb, cs, f32::min),
+        BlendMode::Lighten => separable(cb, cs, f32::max),
+        BlendMode::ColorDodge => separable(cb, cs, color_dodge),
+        BlendMode::ColorBurn => separable(cb, cs, color_burn),
+        BlendMode::HardLight => separable(cb, cs, hard_light),
+        BlendMode::SoftLight => separable(cb, cs, soft_light),
+        BlendMode::Difference => separable(cb, cs, |cb, cs| (cb - cs).abs()),
+        BlendMode::Exclusion => separable(cb, cs, |cb, cs| cb + cs - 2.0 * cb * cs),
+        BlendMode::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+        BlendMode::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+        BlendMode::Color => set_lum(cs, lum(cb)),
+        BlendMode::Luminosity => set_lum(cb, lum(cs))
+    };
+
+    // Cs' = (1 - αb) * Cs + αb * B(Cb, Cs), premultiplied with αs
+    let source = |c: usize| s.a * ((1.0 - b.a) * cs[c] + b.a * mixed[c]);
+
+    NormalizedPremultipliedRGBAColor{
+        r: source(0) + b.r * (1.0 - s.a),
+        g: source(1) + b.g * (1.0 - s.a),
+        b: source(2) + b.b * (1.0 - s.a),
+        a: s.a + b.a * (1.0 - s.a)
+    }
+}
+
+#[inline]
+fn unpremultiply(f: NormalizedPremultipliedRGBAColor) -> [f32; 3] {
+    let c = NormalizedRGBAColor::from(f);
+    [c.r, c.g, c.b]
+}
+
+#[inline]
+fn separable(cb: [f32; 3], cs: [f32; 3], f: impl Fn(f32, f32) -> f32) -> [f32; 3] {
+    [f(cb[0], cs[0]), f(cb[1], cs[1]), f(cb[2], cs[2])]
+}
+
+fn screen(cb: f32, cs: f32) -> f32 {
+    cb + cs - cb * cs
+}
+
+fn hard_light(cb: f32, cs: f32) -> f32 {
+    if cs <= 0.5 {
+        cb * 2.0 * cs
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+fn color_dodge(cb: f32, cs: f32) -> f32 {
+    if cb == 0.0 {
+        0.0
+    } else if cs >= 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+}
+
+fn color_burn(cb: f32, cs: f32) -> f32 {
+    if cb >= 1.0 {
+        1.0
+    } else if cs == 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+}
+
+fn soft_light(cb: f32, cs: f32) -> f32 {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        let d = if cb <= 0.25 {
+            ((16.0 * cb - 12.0) * cb + 4.0) * cb
+        } else {
+            cb.sqrt()
+        };
+        cb + (2.0 * cs - 1.0) * (d - cb)
+    }
+}
+
+#[inline]
+fn lum(c: [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    let mut c = c;
+    if n < 0.0 {
+        c = c.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        c = c.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+#[inline]
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let mut index = [0, 1, 2];
+    index.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = index;
+
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+    result
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn assert_rgba_eq(a: NormalizedRGBAColor, b: NormalizedRGBAColor, context: impl std::fmt::Debug) {
+        assert!((a.r - b.r).abs() < 1e-5, "{:?}: {:?} != {:?}", context, a, b);
+        assert!((a.g - b.g).abs() < 1e-5, "{:?}: {:?} != {:?}", context, a, b);
+        assert!((a.b - b.b).abs() < 1e-5, "{:?}: {:?} != {:?}", context, a, b);
+        assert!((a.a - b.a).abs() < 1e-5, "{:?}: {:?} != {:?}", context, a, b);
+    }
+
+    static RED: NormalizedRGBAColor = NormalizedRGBAColor{ r: 1.0, g: 0.0, b: 0.0, a: 0.5 };
+    static BLUE: NormalizedRGBAColor = NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 1.0 };
+    static TRANSPARENT: NormalizedRGBAColor = NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
+
+    #[rustfmt::skip]
+    static OPERATORS: [(CompositeOperator, NormalizedRGBAColor); 13] = [
+        (CompositeOperator::Clear,           NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 }),
+        (CompositeOperator::Source,          NormalizedRGBAColor{ r: 1.0, g: 0.0, b: 0.0, a: 0.5 }),
+        (CompositeOperator::Destination,     NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 1.0 }),
+        (CompositeOperator::SourceOver,      NormalizedRGBAColor{ r: 0.5, g: 0.0, b: 0.5, a: 1.0 }),
+        (CompositeOperator::DestinationOver, NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 1.0 }),
+        (CompositeOperator::SourceIn,        NormalizedRGBAColor{ r: 1.0, g: 0.0, b: 0.0, a: 0.5 }),
+        (CompositeOperator::DestinationIn,   NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 0.5 }),
+        (CompositeOperator::SourceOut,       NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 }),
+        (CompositeOperator::DestinationOut,  NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 0.5 }),
+        (CompositeOperator::SourceAtop,      NormalizedRGBAColor{ r: 0.5, g: 0.0, b: 0.5, a: 1.0 }),
+        (CompositeOperator::DestinationAtop, NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 0.5 }),
+        (CompositeOperator::Xor,             NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 1.0, a: 0.5 }),
+        (CompositeOperator::Lighter,         NormalizedRGBAColor{ r: 0.5, g: 0.0, b: 1.0, a: 1.0 }),
+    ];
+
+    #[test]
+    fn porter_duff() {
+        for test in OPERATORS.iter() {
+            assert_rgba_eq(test.1, RED.composite(BLUE, test.0), test.0);
+        }
+    }
+
+    #[test]
+    fn composite_onto_transparent() {
+        assert_rgba_eq(RED, RED.composite(TRANSPARENT, CompositeOperator::SourceOver), CompositeOperator::SourceOver);
+        assert_rgba_eq(TRANSPARENT, RED.composite(TRANSPARENT, CompositeOperator::SourceIn), CompositeOperator::SourceIn);
+    }
+
+    #[rustfmt::skip]
+    static BLEND_MODES: [(BlendMode, NormalizedRGBAColor); 16] = [
+        (BlendMode::Normal,     NormalizedRGBAColor{ r: 0.8,    g: 0.4,   b: 0.2,    a: 1.0 }),
+        (BlendMode::Multiply,   NormalizedRGBAColor{ r: 0.4,    g: 0.2,   b: 0.02,   a: 1.0 }),
+        (BlendMode::Screen,     NormalizedRGBAColor{ r: 0.9,    g: 0.7,   b: 0.28,   a: 1.0 }),
+        (BlendMode::Overlay,    NormalizedRGBAColor{ r: 0.8,    g: 0.4,   b: 0.04,   a: 1.0 }),
+        (BlendMode::Darken,     NormalizedRGBAColor{ r: 0.5,    g: 0.4,   b: 0.1,    a: 1.0 }),
+        (BlendMode::Lighten,    NormalizedRGBAColor{ r: 0.8,    g: 0.5,   b: 0.2,    a: 1.0 }),
+        (BlendMode::ColorDodge, NormalizedRGBAColor{ r: 1.0,    g: 0.833333, b: 0.125, a: 1.0 }),
+        (BlendMode::ColorBurn,  NormalizedRGBAColor{ r: 0.375,  g: 0.0,   b: 0.0,    a: 1.0 }),
+        (BlendMode::HardLight,  NormalizedRGBAColor{ r: 0.8,    g: 0.4,   b: 0.04,   a: 1.0 }),
+        (BlendMode::SoftLight,  NormalizedRGBAColor{ r: 0.624264, g: 0.45,  b: 0.046,  a: 1.0 }),
+        (BlendMode::Difference, NormalizedRGBAColor{ r: 0.3,    g: 0.1,   b: 0.1,    a: 1.0 }),
+        (BlendMode::Exclusion,  NormalizedRGBAColor{ r: 0.5,    g: 0.5,   b: 0.26,   a: 1.0 }),
+        (BlendMode::Hue,        NormalizedRGBAColor{ r: 0.657333, g: 0.390667, b: 0.257333, a: 1.0 }),
+        (BlendMode::Saturation, NormalizedRGBAColor{ r: 0.512360, g: 0.512360, b: 0.0, a: 1.0 }),
+        (BlendMode::Color,      NormalizedRGBAColor{ r: 0.758,  g: 0.358, b: 0.158,  a: 1.0 }),
+        (BlendMode::Luminosity, NormalizedRGBAColor{ r: 0.542,  g: 0.542, b: 0.142,  a: 1.0 }),
+    ];
+
+    #[test]
+    fn blend_modes() {
+        let source = NormalizedRGBAColor{ r: 0.8, g: 0.4, b: 0.2, a: 1.0 };
+        let backdrop = NormalizedRGBAColor{ r: 0.5, g: 0.5, b: 0.1, a: 1.0 };
+        for test in BLEND_MODES.iter() {
+            assert_rgba_eq(test.1, source.blend(backdrop, test.0), test.0);
+        }
+    }
+
+    #[test]
+    fn blend_with_alpha() {
+        let source = NormalizedRGBAColor{ r: 1.0, g: 1.0, b: 1.0, a: 0.5 };
+        let backdrop = NormalizedRGBAColor{ r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+        assert_rgba_eq(
+            NormalizedRGBAColor{ r: 0.5, g: 0.5, b: 0.5, a: 1.0 },
+            source.blend(backdrop, BlendMode::Multiply),
+            BlendMode::Multiply
+        );
+        assert_rgba_eq(source, source.blend(TRANSPARENT, BlendMode::Multiply), BlendMode::Multiply);
+    }
+
+    #[test]
+    fn composite_slice() {
+        let source = [RED, RED];
+        let mut backdrop = [BLUE, TRANSPARENT];
+        NormalizedRGBAColor::composite_slice(&source, &mut backdrop, CompositeOperator::SourceOver).unwrap();
+        assert_rgba_eq(RED.composite(BLUE, CompositeOperator::SourceOver), backdrop[0], 0);
+        assert_rgba_eq(RED, backdrop[1], 1);
+
+        let mut short = [BLUE];
+        assert_eq!(
+            Err(ColorConversionError::BufferTooSmall),
+            NormalizedRGBAColor::blend_slice(&source, &mut short, BlendMode::Screen)
+        );
+    }
+}
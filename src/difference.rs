@@ -0,0 +1,261 @@
+use crate::*;
+
+//
+// Color difference metrics
+//
+
+impl<T: Copy> ColorDifference for T where CIELabColor: From<T> {
+    fn delta_e76(self, other: Self) -> f32 {
+        delta_e76(lab(self), lab(other)) as f32
+    }
+
+    fn delta_e94(self, other: Self, application: CIE94Application) -> f32 {
+        delta_e94(lab(self), lab(other), application) as f32
+    }
+
+    fn delta_e2000(self, other: Self) -> f32 {
+        delta_e2000(lab(self), lab(other)) as f32
+    }
+
+    fn delta_e_cmc(self, other: Self, lightness: f32, chroma: f32) -> f32 {
+        delta_e_cmc(lab(self), lab(other), lightness as f64, chroma as f64) as f32
+    }
+}
+
+/// Convert to Lab with `f64` components
+#[inline]
+fn lab<T>(f: T) -> [f64; 3] where CIELabColor: From<T> {
+    let lab = CIELabColor::from(f);
+    [lab.l as f64, lab.a as f64, lab.b as f64]
+}
+
+/// using [CIE76](http://www.brucelindbloom.com/index.html?Eqn_DeltaE_CIE76.html)
+fn delta_e76(c1: [f64; 3], c2: [f64; 3]) -> f64 {
+    let dl = c1[0] - c2[0];
+    let da = c1[1] - c2[1];
+    let db = c1[2] - c2[2];
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Chroma and hue differences of two Lab colors, returns (C1, C2, ΔC, ΔH²)
+#[inline]
+fn chroma_hue_difference(c1: [f64; 3], c2: [f64; 3]) -> (f64, f64, f64, f64) {
+    let ch1 = c1[1].hypot(c1[2]);
+    let ch2 = c2[1].hypot(c2[2]);
+    let dc = ch1 - ch2;
+    let da = c1[1] - c2[1];
+    let db = c1[2] - c2[2];
+
+    (ch1, ch2, dc, (da * da + db * db - dc * dc).max(0.0))
+}
+
+/// using [CIE94](http://www.brucelindbloom.com/index.html?Eqn_DeltaE_CIE94.html)
+fn delta_e94(c1: [f64; 3], c2: [f64; 3], application: CIE94Application) -> f64 {
+    let (kl, k1, k2) = match application {
+        CIE94Application::GraphicArts => (1.0, 0.045, 0.015),
+        CIE94Application::Textiles => (2.0, 0.048, 0.014)
+    };
+    let (ch1, _, dc, dh2) = chroma_hue_difference(c1, c2);
+
+    let sc = 1.0 + k1 * ch1;
+    let sh = 1.0 + k2 * ch1;
+    let dl = (c1[0] - c2[0]) / kl;
+
+    (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+/// using [CIEDE2000](http://www2.ece.rochester.edu/~gsharma/ciede2000/ciede2000noteCRNA.pdf)
+fn delta_e2000(c1: [f64; 3], c2: [f64; 3]) -> f64 {
+    let pow25_7 = 25.0f64.powi(7);
+
+    let c_bar = (c1[1].hypot(c1[2]) + c2[1].hypot(c2[2])) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+
+    let a1 = (1.0 + g) * c1[1];
+    let a2 = (1.0 + g) * c2[1];
+    let ch1 = a1.hypot(c1[2]);
+    let ch2 = a2.hypot(c2[2]);
+    let h1 = hue_angle(a1, c1[2]);
+    let h2 = hue_angle(a2, c2[2]);
+
+    let dl = c2[0] - c1[0];
+    let dc = ch2 - ch1;
+    let dh = if ch1 * ch2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * (ch1 * ch2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (c1[0] + c2[0]) / 2.0;
+    let c_bar = (ch1 + ch2) / 2.0;
+    let h_bar = if ch1 * ch2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0
+        - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+    let l50 = (l_bar - 50.0).powi(2);
+    let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let l = dl / sl;
+    let c = dc / sc;
+    let h = dh / sh;
+
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+/// using [CMC l:c](http://www.brucelindbloom.com/index.html?Eqn_DeltaE_CMC.html)
+fn delta_e_cmc(c1: [f64; 3], c2: [f64; 3], lightness: f64, chroma: f64) -> f64 {
+    let (ch1, _, dc, dh2) = chroma_hue_difference(c1, c2);
+    let h1 = hue_angle(c1[1], c1[2]);
+
+    let sl = if c1[0] < 16.0 {
+        0.511
+    } else {
+        0.040975 * c1[0] / (1.0 + 0.01765 * c1[0])
+    };
+    let sc = 0.0638 * ch1 / (1.0 + 0.0131 * ch1) + 0.638;
+    let f = (ch1.powi(4) / (ch1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+
+    let dl = (c1[0] - c2[0]) / (lightness * sl);
+    let dc = dc / (chroma * sc);
+
+    (dl * dl + dc * dc + dh2 / (sh * sh)).sqrt()
+}
+
+/// Hue angle in degrees, range: 0.0 to 360.0
+#[inline]
+fn hue_angle(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let h = b.atan2(a).to_degrees();
+    if h < 0.0 { h + 360.0 } else { h }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// CIEDE2000 test data from Sharma, Wu and Dalal (2005)
+    #[rustfmt::skip]
+    static SHARMA_DATA: [([f32; 3], [f32; 3], f32); 34] = [
+        ([50.0000,   2.6772, -79.7751], [50.0000,   0.0000, -82.7485],  2.0425),
+        ([50.0000,   3.1571, -77.2803], [50.0000,   0.0000, -82.7485],  2.8615),
+        ([50.0000,   2.8361, -74.0200], [50.0000,   0.0000, -82.7485],  3.4412),
+        ([50.0000,  -1.3802, -84.2814], [50.0000,   0.0000, -82.7485],  1.0000),
+        ([50.0000,  -1.1848, -84.8006], [50.0000,   0.0000, -82.7485],  1.0000),
+        ([50.0000,  -0.9009, -85.5211], [50.0000,   0.0000, -82.7485],  1.0000),
+        ([50.0000,   0.0000,   0.0000], [50.0000,  -1.0000,   2.0000],  2.3669),
+        ([50.0000,  -1.0000,   2.0000], [50.0000,   0.0000,   0.0000],  2.3669),
+        ([50.0000,   2.4900,  -0.0010], [50.0000,  -2.4900,   0.0009],  7.1792),
+        ([50.0000,   2.4900,  -0.0010], [50.0000,  -2.4900,   0.0010],  7.1792),
+        ([50.0000,   2.4900,  -0.0010], [50.0000,  -2.4900,   0.0011],  7.2195),
+        ([50.0000,   2.4900,  -0.0010], [50.0000,  -2.4900,   0.0012],  7.2195),
+        ([50.0000,  -0.0010,   2.4900], [50.0000,   0.0009,  -2.4900],  4.8045),
+        ([50.0000,  -0.0010,   2.4900], [50.0000,   0.0010,  -2.4900],  4.8045),
+        ([50.0000,  -0.0010,   2.4900], [50.0000,   0.0011,  -2.4900],  4.7461),
+        ([50.0000,   2.5000,   0.0000], [50.0000,   0.0000,  -2.5000],  4.3065),
+        ([50.0000,   2.5000,   0.0000], [73.0000,  25.0000, -18.0000], 27.1492),
+        ([50.0000,   2.5000,   0.0000], [61.0000,  -5.0000,  29.0000], 22.8977),
+        ([50.0000,   2.5000,   0.0000], [56.0000, -27.0000,  -3.0000], 31.9030),
+        ([50.0000,   2.5000,   0.0000], [58.0000,  24.0000,  15.0000], 19.4535),
+        ([50.0000,   2.5000,   0.0000], [50.0000,   3.1736,   0.5854],  1.0000),
+        ([50.0000,   2.5000,   0.0000], [50.0000,   3.2972,   0.0000],  1.0000),
+        ([50.0000,   2.5000,   0.0000], [50.0000,   1.8634,   0.5757],  1.0000),
+        ([50.0000,   2.5000,   0.0000], [50.0000,   3.2592,   0.3350],  1.0000),
+        ([60.2574, -34.0099,  36.2677], [60.4626, -34.1751,  39.4387],  1.2644),
+        ([63.0109, -31.0961,  -5.8663], [62.8187, -29.7946,  -4.0864],  1.2630),
+        ([61.2901,   3.7196,  -5.3901], [61.4292,   2.2480,  -4.9620],  1.8731),
+        ([35.0831, -44.1164,   3.7933], [35.0232, -40.0716,   1.5901],  1.8645),
+        ([22.7233,  20.0904, -46.6940], [23.0331,  14.9730, -42.5619],  2.0373),
+        ([36.4612,  47.8580,  18.3852], [36.2715,  50.5065,  21.2231],  1.4146),
+        ([90.8027,  -2.0831,   1.4410], [91.1528,  -1.6435,   0.0447],  1.4441),
+        ([90.9257,  -0.5406,  -0.9208], [88.6381,  -0.8985,  -0.7239],  1.5381),
+        ([ 6.7747,  -0.2908,  -2.4247], [ 5.8714,  -0.0985,  -2.2286],  0.6377),
+        ([ 2.0776,   0.0795,  -1.1350], [ 0.9033,  -0.0636,  -0.5514],  0.9082),
+    ];
+
+    fn lab(c: [f32; 3]) -> CIELabColor {
+        CIELabColor{ l: c[0], a: c[1], b: c[2] }
+    }
+
+    #[test]
+    fn delta_e2000_sharma() {
+        for test in SHARMA_DATA.iter() {
+            let d = lab(test.0).delta_e2000(lab(test.1));
+            assert!((d - test.2).abs() < 1e-4, "{:?}: {}", test, d);
+            let d = lab(test.1).delta_e2000(lab(test.0));
+            assert!((d - test.2).abs() < 1e-4, "{:?}: {}", test, d);
+        }
+    }
+
+    #[test]
+    fn delta_e76() {
+        let d = lab([50.0, 0.0, 0.0]).delta_e76(lab([50.0, 3.0, 4.0]));
+        assert_eq!(5.0, d);
+    }
+
+    #[test]
+    fn delta_e94_lightness() {
+        let a = lab([50.0, 10.0, 10.0]);
+        let b = lab([60.0, 10.0, 10.0]);
+        assert!((a.delta_e94(b, CIE94Application::GraphicArts) - 10.0).abs() < 1e-5);
+        assert!((a.delta_e94(b, CIE94Application::Textiles) - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn delta_e94_chroma() {
+        let a = lab([50.0, 0.0, 20.0]);
+        let b = lab([50.0, 0.0, 30.0]);
+        let d = a.delta_e94(b, CIE94Application::GraphicArts);
+        assert!((d - 10.0 / 1.9).abs() < 1e-5, "{}", d);
+    }
+
+    #[test]
+    fn delta_e_cmc_lightness() {
+        let a = lab([50.0, 0.0, 0.0]);
+        let b = lab([60.0, 0.0, 0.0]);
+        let d = a.delta_e_cmc(b, 2.0, 1.0);
+        assert!((d - 4.5943).abs() < 1e-4, "{}", d);
+    }
+
+    #[test]
+    fn difference_of_rgb_colors() {
+        let a = DigitalRGBColor{ r: 255, g: 0, b: 0 };
+        let b = DigitalRGBColor{ r: 250, g: 10, b: 5 };
+        assert_eq!(0.0, a.delta_e2000(a));
+        let expected = CIELabColor::from(a).delta_e2000(CIELabColor::from(b));
+        assert_eq!(expected, a.delta_e2000(b));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::*;
+
+/// Fractional bits of the fixed-point coefficients (libjpeg `SCALEBITS`)
+const SCALE_BITS: u32 = 16;
+const ONE_HALF: i32 = 1 << (SCALE_BITS - 1);
+const CBCR_OFFSET: i32 = 128 << SCALE_BITS;
+
+/// Convert a coefficient to fixed-point, rounding half away from zero like libjpeg `FIX`
+#[inline]
+fn fix(x: f64) -> i32 {
+    (x * (1 << SCALE_BITS) as f64).round() as i32
+}
+
+#[inline]
+fn descale(v: i32) -> u8 {
+    (v >> SCALE_BITS).clamp(0, 255) as u8
+}
+
+//
+// Fixed-point coefficients
+//
+
+/// Fixed-point coefficients of a YCbCr matrix in a quantization range
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct FixedYCbCr {
+    /// rows of the y, cb and cr coefficients for the r, g and b components
+    forward: [[i32; 3]; 3],
+    /// offset of the luma component
+    y_offset: i32,
+    /// luma to rgb scale
+    y_scale: i32,
+    /// cr to r, cb to g, cr to g and cb to b coefficients
+    inverse: [i32; 4]
+}
+
+impl FixedYCbCr {
+    pub(crate) fn new(matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        let (y_range, y_offset, c_range) = match range {
+            YCbCrRange::Full => (255.0, 0, 255.0),
+            YCbCrRange::Limited => (219.0, 16, 224.0)
+        };
+
+        let kr = matrix.kr() as f64;
+        let kb = matrix.kb() as f64;
+        let kg = 1.0 - kr - kb;
+        let cb_scale = c_range / (255.0 * 2.0 * (1.0 - kb));
+        let cr_scale = c_range / (255.0 * 2.0 * (1.0 - kr));
+
+        let cb_b = 2.0 * (1.0 - kb) * 255.0 / c_range;
+        let cr_r = 2.0 * (1.0 - kr) * 255.0 / c_range;
+
+        let (forward, inverse) = if matrix == YCbCrMatrix::BT601 && range == YCbCrRange::Full {
+            // libjpeg `rgb_ycc_start` and `build_ycc_rgb_table` use rounded decimal constants
+            (
+                [
+                    [fix(0.29900), fix(0.58700), fix(0.11400)],
+                    [-fix(0.16874), -fix(0.33126), fix(0.50000)],
+                    [fix(0.50000), -fix(0.41869), -fix(0.08131)]
+                ],
+                [fix(1.40200), -fix(0.34414), -fix(0.71414), fix(1.77200)]
+            )
+        } else {
+            let y_scale = y_range / 255.0;
+            (
+                [
+                    [fix(kr * y_scale), fix(kg * y_scale), fix(kb * y_scale)],
+                    [fix(-kr * cb_scale), fix(-kg * cb_scale), fix((1.0 - kb) * cb_scale)],
+                    [fix((1.0 - kr) * cr_scale), fix(-kg * cr_scale), fix(-kb * cr_scale)]
+                ],
+                [fix(cr_r), fix(-kb * cb_b / kg), fix(-kr * cr_r / kg), fix(cb_b)]
+            )
+        };
+
+        FixedYCbCr{
+            forward,
+            y_offset,
+            y_scale: fix(255.0 / y_range),
+            inverse
+        }
+    }
+
+    /// Convert a RGB color to YCbCr, the chroma components are rounded down at exactly one half like libjpeg does
+    #[inline]
+    pub(crate) fn encode(&self, f: DigitalRGBColor) -> DigitalYCbCrColor {
+        let (r, g, b) = (f.r as i32, f.g as i32, f.b as i32);
+        let [y, cb, cr] = self.forward;
+
+        DigitalYCbCrColor{
+            y: descale(y[0] * r + y[1] * g + y[2] * b + (self.y_offset << SCALE_BITS) + ONE_HALF),
+            cb: descale(cb[0] * r + cb[1] * g + cb[2] * b + CBCR_OFFSET + ONE_HALF - 1),
+            cr: descale(cr[0] * r + cr[1] * g + cr[2] * b + CBCR_OFFSET + ONE_HALF - 1)
+        }
+    }
+
+    /// Convert a YCbCr color to RGB like libjpeg `ycc_rgb_convert`
+    #[inline]
+    pub(crate) fn decode(&self, f: DigitalYCbCrColor) -> DigitalRGBColor {
+        let y = (f.y as i32 - self.y_offset) * self.y_scale + ONE_HALF;
+        let cb = f.cb as i32 - 128;
+        let cr = f.cr as i32 - 128;
+        let [cr_r, cb_g, cr_g, cb_b] = self.inverse;
+
+        DigitalRGBColor{
+            r: descale(y + cr_r * cr),
+            g: descale(y + cb_g * cb + cr_g * cr),
+            b: descale(y + cb_b * cb)
+        }
+    }
+}
+
+//
+// Batch conversions
+//
+
+pub(crate) fn rgb_to_ycbcr(items: &[DigitalRGBColor], output: &mut [DigitalYCbCrColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) {
+    match arithmetic {
+        YCbCrArithmetic::Float => {
+            for (o, c) in output.iter_mut().zip(items) {
+                *o = DigitalYCbCrColor::from_rgb(*c, matrix, range);
+            }
+        },
+        YCbCrArithmetic::FixedPoint => {
+            let fixed = FixedYCbCr::new(matrix, range);
+            for (o, c) in output.iter_mut().zip(items) {
+                *o = fixed.encode(*c);
+            }
+        }
+    }
+}
+
+pub(crate) fn ycbcr_to_rgb(items: &[DigitalYCbCrColor], output: &mut [DigitalRGBColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) {
+    match arithmetic {
+        YCbCrArithmetic::Float => {
+            for (o, c) in output.iter_mut().zip(items) {
+                *o = DigitalRGBColor::from_ycbcr(*c, matrix, range);
+            }
+        },
+        YCbCrArithmetic::FixedPoint => {
+            let fixed = FixedYCbCr::new(matrix, range);
+            for (o, c) in output.iter_mut().zip(items) {
+                *o = fixed.decode(*c);
+            }
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MATRICES: [YCbCrMatrix; 5] = [
+        YCbCrMatrix::BT601,
+        YCbCrMatrix::BT709,
+        YCbCrMatrix::BT2020,
+        YCbCrMatrix::SMPTE240M,
+        YCbCrMatrix::Custom{ kr: 0.25, kb: 0.125 }
+    ];
+
+    static RANGES: [YCbCrRange; 2] = [YCbCrRange::Full, YCbCrRange::Limited];
+
+    #[test]
+    fn libjpeg_coefficients() {
+        let fixed = FixedYCbCr::new(YCbCrMatrix::BT601, YCbCrRange::Full);
+        assert_eq!([[19595, 38470, 7471], [-11059, -21709, 32768], [32768, -27439, -5329]], fixed.forward);
+        assert_eq!([91881, -22554, -46802, 116130], fixed.inverse);
+        assert_eq!(65536, fixed.y_scale);
+    }
+
+    #[test]
+    fn libjpeg_reference_values() {
+        let red = DigitalRGBColor{ r: 255, g: 0, b: 0 };
+        let ycbcr = DigitalYCbCrColor::from_rgb_fixed(red, YCbCrMatrix::BT601, YCbCrRange::Full);
+        assert_eq!(DigitalYCbCrColor{ y: 76, cb: 85, cr: 255 }, ycbcr);
+        assert_eq!(DigitalRGBColor{ r: 254, g: 0, b: 0 }, DigitalRGBColor::from_ycbcr_fixed(ycbcr, YCbCrMatrix::BT601, YCbCrRange::Full));
+
+        let white = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+        let ycbcr = DigitalYCbCrColor::from_rgb_fixed(white, YCbCrMatrix::BT601, YCbCrRange::Full);
+        assert_eq!(DigitalYCbCrColor{ y: 255, cb: 128, cr: 128 }, ycbcr);
+        assert_eq!(white, DigitalRGBColor::from_ycbcr_fixed(ycbcr, YCbCrMatrix::BT601, YCbCrRange::Full));
+    }
+
+    #[test]
+    fn fixed_point_matches_float() {
+        for matrix in MATRICES.iter() {
+            for range in RANGES.iter() {
+                for r in (0..=255).step_by(15) {
+                    for g in (0..=255).step_by(17) {
+                        for b in (0..=255).step_by(5) {
+                            let rgb = DigitalRGBColor{ r, g, b };
+                            let fixed = DigitalYCbCrColor::from_rgb_fixed(rgb, *matrix, *range);
+                            let float = DigitalYCbCrColor::from_rgb(rgb, *matrix, *range);
+                            assert!((fixed.y as i32 - float.y as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+                            assert!((fixed.cb as i32 - float.cb as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+                            assert!((fixed.cr as i32 - float.cr as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+
+                            let ycbcr = DigitalYCbCrColor{ y: r, cb: g, cr: b };
+                            let fixed = DigitalRGBColor::from_ycbcr_fixed(ycbcr, *matrix, *range);
+                            let float = DigitalRGBColor::from_ycbcr(ycbcr, *matrix, *range);
+                            assert!((fixed.r as i32 - float.r as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+                            assert!((fixed.g as i32 - float.g as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+                            assert!((fixed.b as i32 - float.b as i32).abs() <= 1, "{:?} != {:?}", fixed, float);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn batch_arithmetic() {
+        let items: Vec<DigitalRGBColor> = (0..=255).map(|v| DigitalRGBColor{ r: v, g: 255 - v, b: v / 2 }).collect();
+        for matrix in MATRICES.iter() {
+            for range in RANGES.iter() {
+                let fixed = DigitalRGBColor::convert_vec_ycbcr_with(items.clone(), *matrix, *range, YCbCrArithmetic::FixedPoint);
+                let float = DigitalRGBColor::convert_vec_ycbcr_with(items.clone(), *matrix, *range, YCbCrArithmetic::Float);
+                for ((c, x), f) in items.iter().zip(&fixed).zip(&float) {
+                    assert_eq!(DigitalYCbCrColor::from_rgb_fixed(*c, *matrix, *range), *x);
+                    assert_eq!(DigitalYCbCrColor::from_rgb(*c, *matrix, *range), *f);
+                }
+
+                let mut rgb = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+                DigitalYCbCrColor::convert_slice_rgb_with(&fixed, &mut rgb, *matrix, *range, YCbCrArithmetic::FixedPoint).unwrap();
+                for (c, x) in fixed.iter().zip(&rgb) {
+                    assert_eq!(DigitalRGBColor::from_ycbcr_fixed(*c, *matrix, *range), *x);
+                }
+                assert_eq!(rgb, DigitalYCbCrColor::convert_vec_rgb_with(fixed, *matrix, *range, YCbCrArithmetic::FixedPoint));
+            }
+        }
+
+        let mut output = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; 1];
+        assert_eq!(
+            Err(ColorConversionError::BufferTooSmall),
+            DigitalRGBColor::convert_slice_ycbcr_with(&items, &mut output, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::FixedPoint)
+        );
+    }
+}
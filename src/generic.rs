@@ -0,0 +1,391 @@
+use crate::*;
+use crate::cie::{KAPPA_F64, EPSILON_F64, xyz_to_lab_map_f64};
+use crate::linear::{srgb_to_linear_f64, linear_to_srgb_f64};
+
+//
+// Component type conversion
+//
+
+impl<T: Component> NormalizedRGB<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> NormalizedRGB<U> {
+        NormalizedRGB{
+            r: U::from_f64(self.r.to_f64()),
+            g: U::from_f64(self.g.to_f64()),
+            b: U::from_f64(self.b.to_f64())
+        }
+    }
+}
+
+impl<T: Component> NormalizedRGBA<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> NormalizedRGBA<U> {
+        NormalizedRGBA{
+            r: U::from_f64(self.r.to_f64()),
+            g: U::from_f64(self.g.to_f64()),
+            b: U::from_f64(self.b.to_f64()),
+            a: U::from_f64(self.a.to_f64())
+        }
+    }
+}
+
+impl<T: Component> NormalizedGrayscale<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> NormalizedGrayscale<U> {
+        NormalizedGrayscale{
+            v: U::from_f64(self.v.to_f64())
+        }
+    }
+}
+
+impl<T: Component> NormalizedYCbCr<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> NormalizedYCbCr<U> {
+        NormalizedYCbCr{
+            y: U::from_f64(self.y.to_f64()),
+            cb: U::from_f64(self.cb.to_f64()),
+            cr: U::from_f64(self.cr.to_f64())
+        }
+    }
+}
+
+impl<T: Component> CIEXYZ<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> CIEXYZ<U> {
+        CIEXYZ{
+            x: U::from_f64(self.x.to_f64()),
+            y: U::from_f64(self.y.to_f64()),
+            z: U::from_f64(self.z.to_f64())
+        }
+    }
+}
+
+impl<T: Component> CIELab<T> {
+    /// Convert the components to another component type
+    pub fn cast<U: Component>(self) -> CIELab<U> {
+        CIELab{
+            l: U::from_f64(self.l.to_f64()),
+            a: U::from_f64(self.a.to_f64()),
+            b: U::from_f64(self.b.to_f64())
+        }
+    }
+}
+
+//
+// f32 color types to generic color types
+//
+
+impl<T: Component> From<NormalizedRGBColor> for NormalizedRGB<T> {
+    fn from(f: NormalizedRGBColor) -> Self {
+        NormalizedRGB{ r: f.r, g: f.g, b: f.b }.cast()
+    }
+}
+
+impl<T: Component> From<NormalizedRGBAColor> for NormalizedRGBA<T> {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        NormalizedRGBA{ r: f.r, g: f.g, b: f.b, a: f.a }.cast()
+    }
+}
+
+impl<T: Component> From<NormalizedGrayscaleColor> for NormalizedGrayscale<T> {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        NormalizedGrayscale{ v: f.v }.cast()
+    }
+}
+
+impl<T: Component> From<NormalizedYCbCrColor> for NormalizedYCbCr<T> {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        NormalizedYCbCr{ y: f.y, cb: f.cb, cr: f.cr }.cast()
+    }
+}
+
+impl<T: Component> From<CIEXYZColor> for CIEXYZ<T> {
+    fn from(f: CIEXYZColor) -> Self {
+        CIEXYZ{ x: f.x, y: f.y, z: f.z }.cast()
+    }
+}
+
+impl<T: Component> From<CIELabColor> for CIELab<T> {
+    fn from(f: CIELabColor) -> Self {
+        CIELab{ l: f.l, a: f.a, b: f.b }.cast()
+    }
+}
+
+//
+// Generic color types to f32 color types
+//
+
+impl<T: Component> From<NormalizedRGB<T>> for NormalizedRGBColor {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        let c = f.cast::<f32>();
+        NormalizedRGBColor{ r: c.r, g: c.g, b: c.b }
+    }
+}
+
+impl<T: Component> From<NormalizedRGBA<T>> for NormalizedRGBAColor {
+    fn from(f: NormalizedRGBA<T>) -> Self {
+        let c = f.cast::<f32>();
+        NormalizedRGBAColor{ r: c.r, g: c.g, b: c.b, a: c.a }
+    }
+}
+
+impl<T: Component> From<NormalizedGrayscale<T>> for NormalizedGrayscaleColor {
+    fn from(f: NormalizedGrayscale<T>) -> Self {
+        NormalizedGrayscaleColor{ v: f.cast::<f32>().v }
+    }
+}
+
+impl<T: Component> From<NormalizedYCbCr<T>> for NormalizedYCbCrColor {
+    fn from(f: NormalizedYCbCr<T>) -> Self {
+        let c = f.cast::<f32>();
+        NormalizedYCbCrColor{ y: c.y, cb: c.cb, cr: c.cr }
+    }
+}
+
+impl<T: Component> From<CIEXYZ<T>> for CIEXYZColor {
+    fn from(f: CIEXYZ<T>) -> Self {
+        let c = f.cast::<f32>();
+        CIEXYZColor{ x: c.x, y: c.y, z: c.z }
+    }
+}
+
+impl<T: Component> From<CIELab<T>> for CIELabColor {
+    fn from(f: CIELab<T>) -> Self {
+        let c = f.cast::<f32>();
+        CIELabColor{ l: c.l, a: c.a, b: c.b }
+    }
+}
+
+//
+// Generic RGB, RGBA and grayscale
+//
+
+impl<T: Component> From<NormalizedRGBA<T>> for NormalizedRGB<T> {
+    fn from(f: NormalizedRGBA<T>) -> Self {
+        NormalizedRGB{ r: f.r, g: f.g, b: f.b }
+    }
+}
+
+impl<T: Component> From<NormalizedRGB<T>> for NormalizedRGBA<T> {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        NormalizedRGBA{ r: f.r, g: f.g, b: f.b, a: T::from_f64(1.0) }
+    }
+}
+
+impl<T: Component> From<NormalizedGrayscale<T>> for NormalizedRGB<T> {
+    fn from(f: NormalizedGrayscale<T>) -> Self {
+        NormalizedRGB{ r: f.v, g: f.v, b: f.v }
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl<T: Component> From<NormalizedRGB<T>> for NormalizedGrayscale<T> {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        NormalizedGrayscale{
+            v: T::from_f64(0.299 * f.r.to_f64() + 0.587 * f.g.to_f64() + 0.114 * f.b.to_f64())
+        }
+    }
+}
+
+//
+// Generic RGB and YCbCr
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl<T: Component> From<NormalizedRGB<T>> for NormalizedYCbCr<T> {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        let (r, g, b) = (f.r.to_f64(), f.g.to_f64(), f.b.to_f64());
+        let y = 0.299 * r + 0.587 * g + 0.114 * b;
+
+        NormalizedYCbCr{
+            y: T::from_f64(y),
+            cb: T::from_f64((b - y) / 1.772),
+            cr: T::from_f64((r - y) / 1.402)
+        }
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl<T: Component> From<NormalizedYCbCr<T>> for NormalizedRGB<T> {
+    fn from(f: NormalizedYCbCr<T>) -> Self {
+        let (y, cb, cr) = (f.y.to_f64(), f.cb.to_f64(), f.cr.to_f64());
+        let r = y + 1.402 * cr;
+        let b = y + 1.772 * cb;
+
+        NormalizedRGB{
+            r: T::from_f64(r),
+            g: T::from_f64((y - 0.299 * r - 0.114 * b) / 0.587),
+            b: T::from_f64(b)
+        }
+    }
+}
+
+//
+// Generic RGB and CIE XYZ
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl<T: Component> From<NormalizedRGB<T>> for CIEXYZ<T> {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        let r = srgb_to_linear_f64(f.r.to_f64());
+        let g = srgb_to_linear_f64(f.g.to_f64());
+        let b = srgb_to_linear_f64(f.b.to_f64());
+
+        CIEXYZ{
+            x: T::from_f64(r * 0.4124564390896921 + g * 0.357576077643909 + b * 0.18043748326639894),
+            y: T::from_f64(r * 0.21267285140562248 + g * 0.715152155287818 + b * 0.07217499330655958),
+            z: T::from_f64(r * 0.019333895582329317 + g * 0.119192025881303 + b * 0.9503040785363677)
+        }
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl<T: Component> From<CIEXYZ<T>> for NormalizedRGB<T> {
+    fn from(f: CIEXYZ<T>) -> Self {
+        let (x, y, z) = (f.x.to_f64(), f.y.to_f64(), f.z.to_f64());
+
+        NormalizedRGB{
+            r: T::from_f64(linear_to_srgb_f64(x * 3.2404541621141054 - y * 1.5371385127977166 - z * 0.4985314095560162)),
+            g: T::from_f64(linear_to_srgb_f64(x * -0.9692660305051868 + y * 1.8760108454466942 + z * 0.04155601753034984)),
+            b: T::from_f64(linear_to_srgb_f64(x * 0.05564343095911469 - y * 0.20402591351675387 + z * 1.0572251882231791))
+        }
+    }
+}
+
+//
+// Generic CIE XYZ and CIE Lab
+//
+
+impl<T: Component> CIEXYZ<T> {
+    /// Convert a Lab color that is relative to the reference white `white` to XYZ
+    pub fn from_lab(f: CIELab<T>, white: WhitePoint) -> Self {
+        let [wx, wy, wz] = white.xyz_f64();
+        let l = f.l.to_f64();
+        let fy = (l + 16.0) / 116.0;
+        let fx = f.a.to_f64() / 500.0 + fy;
+        let fz = fy - f.b.to_f64() / 200.0;
+        let xr = if fx.powi(3) > EPSILON_F64 { fx.powi(3) } else { (116.0 * fx - 16.0) / KAPPA_F64 };
+        let yr = if l > EPSILON_F64 * KAPPA_F64 { fy.powi(3) } else { l / KAPPA_F64 };
+        let zr = if fz.powi(3) > EPSILON_F64 { fz.powi(3) } else { (116.0 * fz - 16.0) / KAPPA_F64 };
+
+        CIEXYZ{
+            x: T::from_f64(xr * wx),
+            y: T::from_f64(yr * wy),
+            z: T::from_f64(zr * wz)
+        }
+    }
+}
+
+impl<T: Component> CIELab<T> {
+    /// Convert a XYZ color to Lab relative to the reference white `white`
+    pub fn from_xyz(f: CIEXYZ<T>, white: WhitePoint) -> Self {
+        let [wx, wy, wz] = white.xyz_f64();
+        let x = xyz_to_lab_map_f64(f.x.to_f64() / wx);
+        let y = xyz_to_lab_map_f64(f.y.to_f64() / wy);
+        let z = xyz_to_lab_map_f64(f.z.to_f64() / wz);
+
+        CIELab{
+            l: T::from_f64(116.0 * y - 16.0),
+            a: T::from_f64(500.0 * (x - y)),
+            b: T::from_f64(200.0 * (y - z))
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl<T: Component> From<CIELab<T>> for CIEXYZ<T> {
+    fn from(f: CIELab<T>) -> Self {
+        CIEXYZ::from_lab(f, WhitePoint::D65)
+    }
+}
+
+/// using a `D65` reference white
+impl<T: Component> From<CIEXYZ<T>> for CIELab<T> {
+    fn from(f: CIEXYZ<T>) -> Self {
+        CIELab::from_xyz(f, WhitePoint::D65)
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) and a `D65` reference white
+impl<T: Component> From<NormalizedRGB<T>> for CIELab<T> {
+    fn from(f: NormalizedRGB<T>) -> Self {
+        let xyz = CIEXYZ::<f64>::from(f.cast::<f64>());
+        CIELab::<f64>::from(xyz).cast()
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) and a `D65` reference white
+impl<T: Component> From<CIELab<T>> for NormalizedRGB<T> {
+    fn from(f: CIELab<T>) -> Self {
+        let xyz = CIEXYZ::<f64>::from(f.cast::<f64>());
+        NormalizedRGB::<f64>::from(xyz).cast()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rgb_f64_2_lab_f64() {
+        let white = NormalizedRGB{ r: 1.0f64, g: 1.0, b: 1.0 };
+        let lab = CIELab::from(white);
+        assert!((lab.l - 100.0).abs() < 1e-9, "{:?}", lab);
+        assert!(lab.a.abs() < 1e-3, "{:?}", lab);
+        assert!(lab.b.abs() < 1e-3, "{:?}", lab);
+    }
+
+    #[test]
+    fn rgb_f64_lab_roundtrip() {
+        let rgb = NormalizedRGB{ r: 0.2f64, g: 0.6, b: 0.9 };
+        let back = NormalizedRGB::from(CIELab::from(rgb));
+        assert!((back.r - rgb.r).abs() < 1e-12, "{:?} != {:?}", back, rgb);
+        assert!((back.g - rgb.g).abs() < 1e-12, "{:?} != {:?}", back, rgb);
+        assert!((back.b - rgb.b).abs() < 1e-12, "{:?} != {:?}", back, rgb);
+    }
+
+    #[test]
+    fn white_point_f64() {
+        let lab = CIELab::from_xyz(CIEXYZ{ x: 0.96422f64, y: 1.0, z: 0.82521 }, WhitePoint::D50);
+        assert!((lab.l - 100.0).abs() < 1e-12, "{:?}", lab);
+        assert!(lab.a.abs() < 1e-12, "{:?}", lab);
+        assert!(lab.b.abs() < 1e-12, "{:?}", lab);
+
+        let xyz = CIEXYZ::from_lab(CIELab{ l: 100.0f64, a: 0.0, b: 0.0 }, WhitePoint::D50);
+        assert_eq!(CIEXYZ{ x: 0.96422, y: 1.0, z: 0.82521 }, xyz);
+    }
+
+    #[test]
+    fn f32_and_f64_agree() {
+        let rgb = NormalizedRGBColor{ r: 0.8, g: 0.3, b: 0.1 };
+        let lab32 = CIELabColor::from(rgb);
+        let lab64 = CIELab::<f64>::from(NormalizedRGB::<f64>::from(rgb));
+        assert!((lab32.l as f64 - lab64.l).abs() < 1e-3, "{:?} != {:?}", lab32, lab64);
+        assert!((lab32.a as f64 - lab64.a).abs() < 1e-3, "{:?} != {:?}", lab32, lab64);
+        assert!((lab32.b as f64 - lab64.b).abs() < 1e-3, "{:?} != {:?}", lab32, lab64);
+    }
+
+    #[test]
+    fn f16_colors() {
+        let rgba = NormalizedRGBA::<F16>::from(NormalizedRGBAColor{ r: 1.0, g: 0.5, b: 0.25, a: 0.0 });
+        assert_eq!(0x3c00, rgba.r.to_bits());
+        assert_eq!(0x3800, rgba.g.to_bits());
+        assert_eq!(NormalizedRGBAColor{ r: 1.0, g: 0.5, b: 0.25, a: 0.0 }, NormalizedRGBAColor::from(rgba));
+
+        let xyz = CIEXYZ::<F16>::from(NormalizedRGB::<F16>::from(NormalizedRGBColor{ r: 1.0, g: 1.0, b: 1.0 }));
+        assert!((xyz.y.to_f32() - 1.0).abs() < 1e-3, "{:?}", xyz);
+    }
+
+    #[test]
+    fn ycbcr_roundtrip() {
+        let rgb = NormalizedRGB{ r: 0.1f64, g: 0.7, b: 0.4 };
+        let back = NormalizedRGB::from(NormalizedYCbCr::from(rgb));
+        assert!((back.g - rgb.g).abs() < 1e-12, "{:?} != {:?}", back, rgb);
+        let gray = NormalizedGrayscale::from(rgb);
+        assert!((gray.v - NormalizedYCbCr::from(rgb).y).abs() < 1e-15);
+    }
+}
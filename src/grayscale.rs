@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 use crate::*;
+use crate::bit16::{u16_to_f32, u16_to_u8};
 
 //
 // Native types to Grayscale
@@ -25,7 +26,7 @@ impl TryFrom<&[u8]> for DigitalGrayscaleColor {
     type Error = ColorConversionError;
 
     fn try_from(octets: &[u8]) -> Result<Self, ColorConversionError> {
-        if octets.len() < 1 {
+        if octets.is_empty() {
             return Err(ColorConversionError::BufferTooSmall);
         }
         
@@ -55,27 +56,27 @@ impl From<u8> for NormalizedGrayscaleColor {
     }
 }
 
-impl Into<[u8; 1]> for DigitalGrayscaleColor {
-    fn into(self) -> [u8; 1] {
-        [self.v]
+impl From<DigitalGrayscaleColor> for [u8; 1] {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        [f.v]
     }
 }
 
-impl Into<u8> for DigitalGrayscaleColor {
-    fn into(self) -> u8 {
-        self.v
+impl From<DigitalGrayscaleColor> for u8 {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        f.v
     }
 }
 
-impl Into<[u8; 1]> for NormalizedGrayscaleColor {
-    fn into(self) -> [u8; 1] {
-        DigitalGrayscaleColor::from(self).into()
+impl From<NormalizedGrayscaleColor> for [u8; 1] {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        DigitalGrayscaleColor::from(f).into()
     }
 }
 
-impl Into<u8> for NormalizedGrayscaleColor {
-    fn into(self) -> u8 {
-        DigitalGrayscaleColor::from(self).into()
+impl From<NormalizedGrayscaleColor> for u8 {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        DigitalGrayscaleColor::from(f).into()
     }
 }
 
@@ -119,7 +120,7 @@ impl From<DigitalRGBColor> for NormalizedGrayscaleColor {
         let g = f.g as f32;
         let b = f.b as f32;
         NormalizedGrayscaleColor {
-            v: 0.299  * r + 0.587  * g + 0.114  * b
+            v: (0.299  * r + 0.587  * g + 0.114  * b) / 255.0
         }
     }    
 }
@@ -140,7 +141,7 @@ impl From<DigitalRGBColor> for DigitalGrayscaleColor {
         let g = f.g as f32;
         let b = f.b as f32;
         DigitalGrayscaleColor {
-            v: ((0.299  * r + 0.587  * g + 0.114  * b) * 255.0) as u8
+            v: (0.299  * r + 0.587  * g + 0.114  * b).round().clamp(0.0, 255.0) as u8
         }
     }    
 }
@@ -244,65 +245,201 @@ impl From<CIELabColor> for DigitalGrayscaleColor {
 }
 
 
+//
+// HSL to Gray
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<NormalizedHSLColor> for NormalizedGrayscaleColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        NormalizedGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalHSLColor> for NormalizedGrayscaleColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<NormalizedHSLColor> for DigitalGrayscaleColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalHSLColor> for DigitalGrayscaleColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        DigitalGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// HSV to Gray
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<NormalizedHSVColor> for NormalizedGrayscaleColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        NormalizedGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalHSVColor> for NormalizedGrayscaleColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<NormalizedHSVColor> for DigitalGrayscaleColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+impl From<DigitalHSVColor> for DigitalGrayscaleColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        DigitalGrayscaleColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// Gray16 to Gray
+//
+
+impl From<DigitalGrayscale16Color> for NormalizedGrayscaleColor {
+    fn from(f: DigitalGrayscale16Color) -> Self {
+        NormalizedGrayscaleColor{
+            v: u16_to_f32(f.v)
+        }
+    }
+}
+
+impl From<DigitalGrayscale16Color> for DigitalGrayscaleColor {
+    fn from(f: DigitalGrayscale16Color) -> Self {
+        DigitalGrayscaleColor{
+            v: u16_to_u8(f.v)
+        }
+    }
+}
+
 // 
 // Color conversion traits
 //
 
 impl YCbCrConvertible for DigitalGrayscaleColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl YCbCrConvertible for NormalizedGrayscaleColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl RGBConvertible for NormalizedGrayscaleColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBColor::from).collect()
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
     }
 }
 
 impl RGBAConvertible for DigitalGrayscaleColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl RGBAConvertible for NormalizedGrayscaleColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl CIELabConvertible for DigitalGrayscaleColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIELabConvertible for NormalizedGrayscaleColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for DigitalGrayscaleColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for NormalizedGrayscaleColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl HSLConvertible for NormalizedGrayscaleColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for NormalizedGrayscaleColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for DigitalGrayscaleColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for DigitalGrayscaleColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl Grayscale16Convertible for DigitalGrayscaleColor {
+    fn convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color> {
+        items.into_iter().map(DigitalGrayscale16Color::from).collect()
+    }
+}
+
+impl Grayscale16Convertible for NormalizedGrayscaleColor {
+    fn convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color> {
+        items.into_iter().map(DigitalGrayscale16Color::from).collect()
     }
 }
 
@@ -328,6 +465,15 @@ mod tests {
         assert_eq!(DigitalGrayscaleColor{ v: 255 }, rd);
     }
 
+    #[test]
+    fn rgb_d_2_gray() {
+        let rgb = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+        assert_eq!(DigitalGrayscaleColor{ v: 255 }, DigitalGrayscaleColor::from(rgb));
+        assert!((NormalizedGrayscaleColor::from(rgb).v - 1.0).abs() < 1e-6);
+        let rgb = DigitalRGBColor{ r: 100, g: 150, b: 200 };
+        assert_eq!(DigitalGrayscaleColor{ v: 141 }, DigitalGrayscaleColor::from(rgb));
+    }
+
     #[test]
     fn ycbcr_d_2_gray_d() {
         let y = DigitalYCbCrColor{y: 255, cb: 0, cr: 0};
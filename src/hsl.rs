@@ -0,0 +1,463 @@
+use crate::*;
+
+//
+// Native types to HSL
+//
+
+impl From<[u8; 3]> for DigitalHSLColor {
+    fn from(octets: [u8; 3]) -> Self {
+        DigitalHSLColor{
+            h: octets[0],
+            s: octets[1],
+            l: octets[2]
+        }
+    }
+}
+
+impl From<&[u8; 3]> for DigitalHSLColor {
+    fn from(octets: &[u8; 3]) -> Self {
+        DigitalHSLColor{
+            h: octets[0],
+            s: octets[1],
+            l: octets[2]
+        }
+    }
+}
+
+impl From<DigitalHSLColor> for [u8; 3] {
+    fn from(f: DigitalHSLColor) -> Self {
+        [f.h, f.s, f.l]
+    }
+}
+
+//
+// HSL to HSL
+//
+
+impl From<NormalizedHSLColor> for DigitalHSLColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalHSLColor{
+            h: hue_to_u8(f.h),
+            s: (f.s * 255.0).round() as u8,
+            l: (f.l * 255.0).round() as u8
+        }
+    }
+}
+
+impl From<DigitalHSLColor> for NormalizedHSLColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedHSLColor{
+            h: f.h as f32 / 256.0,
+            s: f.s as f32 / 255.0,
+            l: f.l as f32 / 255.0
+        }
+    }
+}
+
+//
+// HSV to HSL
+//
+
+impl From<NormalizedHSVColor> for NormalizedHSLColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        let l = f.v * (1.0 - f.s / 2.0);
+        let m = l.min(1.0 - l);
+
+        NormalizedHSLColor{
+            h: f.h,
+            s: if m > 0.0 { (f.v - l) / m } else { 0.0 },
+            l
+        }
+    }
+}
+
+impl From<DigitalHSVColor> for NormalizedHSLColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedHSLColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+impl From<NormalizedHSVColor> for DigitalHSLColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalHSLColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalHSLColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        DigitalHSLColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+//
+// Gray to HSL
+//
+
+impl From<DigitalGrayscaleColor> for DigitalHSLColor {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        DigitalHSLColor{
+            h: 0,
+            s: 0,
+            l: f.v
+        }
+    }
+}
+
+impl From<DigitalGrayscaleColor> for NormalizedHSLColor {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        NormalizedHSLColor{
+            h: 0.0,
+            s: 0.0,
+            l: (f.v as f32) / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleColor> for DigitalHSLColor {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        DigitalHSLColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+impl From<NormalizedGrayscaleColor> for NormalizedHSLColor {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        NormalizedHSLColor{
+            h: 0.0,
+            s: 0.0,
+            l: f.v
+        }
+    }
+}
+
+//
+// RGB to HSL
+//
+
+impl From<NormalizedRGBColor> for NormalizedHSLColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        let max = f.r.max(f.g).max(f.b);
+        let min = f.r.min(f.g).min(f.b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+
+        if d <= 0.0 {
+            return NormalizedHSLColor{ h: 0.0, s: 0.0, l };
+        }
+
+        NormalizedHSLColor{
+            h: rgb_hue(f, max, d),
+            s: d / (1.0 - (2.0 * l - 1.0).abs()),
+            l
+        }
+    }
+}
+
+impl From<DigitalRGBColor> for NormalizedHSLColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBColor> for DigitalHSLColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalHSLColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+impl From<DigitalRGBColor> for DigitalHSLColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        DigitalHSLColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+/// Calculate the hue of a RGB color as fraction of a full circle
+///
+/// `max` is the largest component of the color, `d` the difference between the largest
+/// and the smallest component, which has to be bigger than zero.
+#[inline]
+pub(crate) fn rgb_hue(f: NormalizedRGBColor, max: f32, d: f32) -> f32 {
+    let h = if max == f.r {
+        ((f.g - f.b) / d).rem_euclid(6.0)
+    } else if max == f.g {
+        (f.b - f.r) / d + 2.0
+    } else {
+        (f.r - f.g) / d + 4.0
+    };
+
+    h / 6.0
+}
+
+/// Map a hue in the range of 0.0 to 1.0 to a `u8`, wrapping around at the full circle
+#[inline]
+pub(crate) fn hue_to_u8(h: f32) -> u8 {
+    ((h * 256.0).round() as i32).rem_euclid(256) as u8
+}
+
+//
+// RGBA to HSL
+//
+
+impl From<DigitalRGBAColor> for NormalizedHSLColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBAColor> for NormalizedHSLColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalHSLColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBAColor> for DigitalHSLColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// YCbCr to HSL
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<NormalizedYCbCrColor> for NormalizedHSLColor {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<DigitalYCbCrColor> for NormalizedHSLColor {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<NormalizedYCbCrColor> for DigitalHSLColor {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<DigitalYCbCrColor> for DigitalHSLColor {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// CIE to HSL
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIEXYZColor> for NormalizedHSLColor {
+    fn from(f: CIEXYZColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIEXYZColor> for DigitalHSLColor {
+    fn from(f: CIEXYZColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIELabColor> for NormalizedHSLColor {
+    fn from(f: CIELabColor) -> Self {
+        NormalizedHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIELabColor> for DigitalHSLColor {
+    fn from(f: CIELabColor) -> Self {
+        DigitalHSLColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl HSLConvertible for NormalizedHSLColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for DigitalHSLColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSVConvertible for NormalizedHSLColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl YCbCrConvertible for DigitalHSLColor {
+    fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+impl YCbCrConvertible for NormalizedHSLColor {
+    fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+impl RGBConvertible for DigitalHSLColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBConvertible for NormalizedHSLColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBAConvertible for DigitalHSLColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedHSLColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for DigitalHSLColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for NormalizedHSLColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for DigitalHSLColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for NormalizedHSLColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn hsl_d_2_hsl_n() {
+        let hd = DigitalHSLColor{ h: 128, s: 255, l: 0 };
+        let hn = NormalizedHSLColor::from(hd);
+        assert_eq!(NormalizedHSLColor{ h: 0.5, s: 1.0, l: 0.0 }, hn);
+    }
+
+    #[test]
+    fn hsl_n_2_hsl_d() {
+        let hn = NormalizedHSLColor{ h: 0.5, s: 1.0, l: 0.5 };
+        let hd = DigitalHSLColor::from(hn);
+        assert_eq!(DigitalHSLColor{ h: 128, s: 255, l: 128 }, hd);
+    }
+
+    #[test]
+    fn hsl_n_2_hsl_d_wraps_hue() {
+        let hn = NormalizedHSLColor{ h: 0.999, s: 1.0, l: 0.5 };
+        let hd = DigitalHSLColor::from(hn);
+        assert_eq!(DigitalHSLColor{ h: 0, s: 255, l: 128 }, hd);
+    }
+
+    #[rustfmt::skip]
+    static HSL_COLOURS: [(DigitalRGBColor, NormalizedHSLColor); 8] = [
+        (DigitalRGBColor { r:   0, g:   0, b:   0 }, NormalizedHSLColor { h: 0.0,        s: 0.0, l: 0.0 }),
+        (DigitalRGBColor { r: 255, g: 255, b: 255 }, NormalizedHSLColor { h: 0.0,        s: 0.0, l: 1.0 }),
+        (DigitalRGBColor { r: 255, g:   0, b:   0 }, NormalizedHSLColor { h: 0.0,        s: 1.0, l: 0.5 }),
+        (DigitalRGBColor { r:   0, g: 255, b:   0 }, NormalizedHSLColor { h: 1.0 / 3.0,  s: 1.0, l: 0.5 }),
+        (DigitalRGBColor { r:   0, g:   0, b: 255 }, NormalizedHSLColor { h: 2.0 / 3.0,  s: 1.0, l: 0.5 }),
+        (DigitalRGBColor { r: 255, g: 255, b:   0 }, NormalizedHSLColor { h: 1.0 / 6.0,  s: 1.0, l: 0.5 }),
+        (DigitalRGBColor { r:   0, g: 255, b: 255 }, NormalizedHSLColor { h: 0.5,        s: 1.0, l: 0.5 }),
+        (DigitalRGBColor { r: 255, g:   0, b: 255 }, NormalizedHSLColor { h: 5.0 / 6.0,  s: 1.0, l: 0.5 }),
+    ];
+
+    #[test]
+    fn rgb_d_2_hsl_n() {
+        for test in HSL_COLOURS.iter() {
+            let hsl = NormalizedHSLColor::from(test.0);
+            assert!((hsl.h - test.1.h).abs() < 1e-6, "{:?} != {:?}", hsl, test.1);
+            assert!((hsl.s - test.1.s).abs() < 1e-6, "{:?} != {:?}", hsl, test.1);
+            assert!((hsl.l - test.1.l).abs() < 1e-6, "{:?} != {:?}", hsl, test.1);
+        }
+    }
+
+    #[test]
+    fn hsl_n_2_rgb_d() {
+        for test in HSL_COLOURS.iter() {
+            assert_eq!(test.0, DigitalRGBColor::from(test.1))
+        }
+    }
+
+    #[test]
+    fn rgb_d_2_hsl_d_2_rgb_d() {
+        let rgb = DigitalRGBColor{ r: 200, g: 100, b: 50 };
+        let hsl = DigitalHSLColor::from(rgb);
+        assert_eq!(DigitalHSLColor{ h: 14, s: 153, l: 125 }, hsl);
+        assert_eq!(DigitalRGBColor{ r: 200, g: 99, b: 49 }, DigitalRGBColor::from(hsl));
+    }
+
+    #[test]
+    fn hsv_n_2_hsl_n() {
+        let hsv = NormalizedHSVColor{ h: 0.25, s: 1.0, v: 1.0 };
+        assert_eq!(NormalizedHSLColor{ h: 0.25, s: 1.0, l: 0.5 }, NormalizedHSLColor::from(hsv));
+        let hsv = NormalizedHSVColor{ h: 0.25, s: 0.0, v: 1.0 };
+        assert_eq!(NormalizedHSLColor{ h: 0.25, s: 0.0, l: 1.0 }, NormalizedHSLColor::from(hsv));
+    }
+}
@@ -0,0 +1,424 @@
+use crate::*;
+use crate::hsl::{rgb_hue, hue_to_u8};
+
+//
+// Native types to HSV
+//
+
+impl From<[u8; 3]> for DigitalHSVColor {
+    fn from(octets: [u8; 3]) -> Self {
+        DigitalHSVColor{
+            h: octets[0],
+            s: octets[1],
+            v: octets[2]
+        }
+    }
+}
+
+impl From<&[u8; 3]> for DigitalHSVColor {
+    fn from(octets: &[u8; 3]) -> Self {
+        DigitalHSVColor{
+            h: octets[0],
+            s: octets[1],
+            v: octets[2]
+        }
+    }
+}
+
+impl From<DigitalHSVColor> for [u8; 3] {
+    fn from(f: DigitalHSVColor) -> Self {
+        [f.h, f.s, f.v]
+    }
+}
+
+//
+// HSV to HSV
+//
+
+impl From<NormalizedHSVColor> for DigitalHSVColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalHSVColor{
+            h: hue_to_u8(f.h),
+            s: (f.s * 255.0).round() as u8,
+            v: (f.v * 255.0).round() as u8
+        }
+    }
+}
+
+impl From<DigitalHSVColor> for NormalizedHSVColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedHSVColor{
+            h: f.h as f32 / 256.0,
+            s: f.s as f32 / 255.0,
+            v: f.v as f32 / 255.0
+        }
+    }
+}
+
+//
+// HSL to HSV
+//
+
+impl From<NormalizedHSLColor> for NormalizedHSVColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        let v = f.l + f.s * f.l.min(1.0 - f.l);
+
+        NormalizedHSVColor{
+            h: f.h,
+            s: if v > 0.0 { 2.0 * (1.0 - f.l / v) } else { 0.0 },
+            v
+        }
+    }
+}
+
+impl From<DigitalHSLColor> for NormalizedHSVColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedHSVColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+impl From<NormalizedHSLColor> for DigitalHSVColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalHSVColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalHSVColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        DigitalHSVColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+//
+// Gray to HSV
+//
+
+impl From<DigitalGrayscaleColor> for DigitalHSVColor {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        DigitalHSVColor{
+            h: 0,
+            s: 0,
+            v: f.v
+        }
+    }
+}
+
+impl From<DigitalGrayscaleColor> for NormalizedHSVColor {
+    fn from(f: DigitalGrayscaleColor) -> Self {
+        NormalizedHSVColor{
+            h: 0.0,
+            s: 0.0,
+            v: (f.v as f32) / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleColor> for DigitalHSVColor {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        DigitalHSVColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+impl From<NormalizedGrayscaleColor> for NormalizedHSVColor {
+    fn from(f: NormalizedGrayscaleColor) -> Self {
+        NormalizedHSVColor{
+            h: 0.0,
+            s: 0.0,
+            v: f.v
+        }
+    }
+}
+
+//
+// RGB to HSV
+//
+
+impl From<NormalizedRGBColor> for NormalizedHSVColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        let max = f.r.max(f.g).max(f.b);
+        let min = f.r.min(f.g).min(f.b);
+        let d = max - min;
+
+        if d <= 0.0 {
+            return NormalizedHSVColor{ h: 0.0, s: 0.0, v: max };
+        }
+
+        NormalizedHSVColor{
+            h: rgb_hue(f, max, d),
+            s: d / max,
+            v: max
+        }
+    }
+}
+
+impl From<DigitalRGBColor> for NormalizedHSVColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBColor> for DigitalHSVColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalHSVColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+impl From<DigitalRGBColor> for DigitalHSVColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        DigitalHSVColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+//
+// RGBA to HSV
+//
+
+impl From<DigitalRGBAColor> for NormalizedHSVColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBAColor> for NormalizedHSVColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalRGBAColor> for DigitalHSVColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBAColor> for DigitalHSVColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// YCbCr to HSV
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<NormalizedYCbCrColor> for NormalizedHSVColor {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<DigitalYCbCrColor> for NormalizedHSVColor {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<NormalizedYCbCrColor> for DigitalHSVColor {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion to RGB
+impl From<DigitalYCbCrColor> for DigitalHSVColor {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// CIE to HSV
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIEXYZColor> for NormalizedHSVColor {
+    fn from(f: CIEXYZColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIEXYZColor> for DigitalHSVColor {
+    fn from(f: CIEXYZColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIELabColor> for NormalizedHSVColor {
+    fn from(f: CIELabColor) -> Self {
+        NormalizedHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html) for conversion to RGB
+impl From<CIELabColor> for DigitalHSVColor {
+    fn from(f: CIELabColor) -> Self {
+        DigitalHSVColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl HSVConvertible for NormalizedHSVColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for DigitalHSVColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSLConvertible for NormalizedHSVColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl YCbCrConvertible for DigitalHSVColor {
+    fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+impl YCbCrConvertible for NormalizedHSVColor {
+    fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+impl RGBConvertible for DigitalHSVColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBConvertible for NormalizedHSVColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBAConvertible for DigitalHSVColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedHSVColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for DigitalHSVColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for NormalizedHSVColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for DigitalHSVColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for NormalizedHSVColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn hsv_d_2_hsv_n() {
+        let hd = DigitalHSVColor{ h: 64, s: 255, v: 0 };
+        let hn = NormalizedHSVColor::from(hd);
+        assert_eq!(NormalizedHSVColor{ h: 0.25, s: 1.0, v: 0.0 }, hn);
+    }
+
+    #[test]
+    fn hsv_n_2_hsv_d() {
+        let hn = NormalizedHSVColor{ h: 0.25, s: 1.0, v: 0.5 };
+        let hd = DigitalHSVColor::from(hn);
+        assert_eq!(DigitalHSVColor{ h: 64, s: 255, v: 128 }, hd);
+    }
+
+    #[rustfmt::skip]
+    static HSV_COLOURS: [(DigitalRGBColor, NormalizedHSVColor); 8] = [
+        (DigitalRGBColor { r:   0, g:   0, b:   0 }, NormalizedHSVColor { h: 0.0,        s: 0.0, v: 0.0 }),
+        (DigitalRGBColor { r: 255, g: 255, b: 255 }, NormalizedHSVColor { h: 0.0,        s: 0.0, v: 1.0 }),
+        (DigitalRGBColor { r: 255, g:   0, b:   0 }, NormalizedHSVColor { h: 0.0,        s: 1.0, v: 1.0 }),
+        (DigitalRGBColor { r:   0, g: 255, b:   0 }, NormalizedHSVColor { h: 1.0 / 3.0,  s: 1.0, v: 1.0 }),
+        (DigitalRGBColor { r:   0, g:   0, b: 255 }, NormalizedHSVColor { h: 2.0 / 3.0,  s: 1.0, v: 1.0 }),
+        (DigitalRGBColor { r: 255, g: 255, b:   0 }, NormalizedHSVColor { h: 1.0 / 6.0,  s: 1.0, v: 1.0 }),
+        (DigitalRGBColor { r:   0, g: 102, b: 102 }, NormalizedHSVColor { h: 0.5,        s: 1.0, v: 0.4 }),
+        (DigitalRGBColor { r: 255, g: 153, b: 255 }, NormalizedHSVColor { h: 5.0 / 6.0,  s: 0.4, v: 1.0 }),
+    ];
+
+    #[test]
+    fn rgb_d_2_hsv_n() {
+        for test in HSV_COLOURS.iter() {
+            let hsv = NormalizedHSVColor::from(test.0);
+            assert!((hsv.h - test.1.h).abs() < 1e-6, "{:?} != {:?}", hsv, test.1);
+            assert!((hsv.s - test.1.s).abs() < 1e-6, "{:?} != {:?}", hsv, test.1);
+            assert!((hsv.v - test.1.v).abs() < 1e-6, "{:?} != {:?}", hsv, test.1);
+        }
+    }
+
+    #[test]
+    fn hsv_n_2_rgb_d() {
+        for test in HSV_COLOURS.iter() {
+            assert_eq!(test.0, DigitalRGBColor::from(test.1))
+        }
+    }
+
+    #[test]
+    fn hsl_n_2_hsv_n() {
+        let hsl = NormalizedHSLColor{ h: 0.75, s: 1.0, l: 0.5 };
+        assert_eq!(NormalizedHSVColor{ h: 0.75, s: 1.0, v: 1.0 }, NormalizedHSVColor::from(hsl));
+        let hsl = NormalizedHSLColor{ h: 0.75, s: 0.0, l: 0.0 };
+        assert_eq!(NormalizedHSVColor{ h: 0.75, s: 0.0, v: 0.0 }, NormalizedHSVColor::from(hsl));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::*;
+
+//
+// Channel order
+//
+
+impl ChannelOrder {
+    /// Bit shifts of the red, green, blue and alpha (or unused) byte in the packed value
+    #[inline]
+    fn shifts(self) -> [u32; 4] {
+        match self {
+            ChannelOrder::RGBA | ChannelOrder::RGBX => [24, 16, 8, 0],
+            ChannelOrder::ARGB | ChannelOrder::XRGB => [16, 8, 0, 24],
+            ChannelOrder::BGRA | ChannelOrder::BGRX => [8, 16, 24, 0],
+            ChannelOrder::ABGR | ChannelOrder::XBGR => [0, 8, 16, 24]
+        }
+    }
+
+    /// Whether the order stores an alpha channel
+    pub fn has_alpha(self) -> bool {
+        matches!(self, ChannelOrder::RGBA | ChannelOrder::ARGB | ChannelOrder::BGRA | ChannelOrder::ABGR)
+    }
+}
+
+impl Endianness {
+    /// Byte order of the target platform
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endianness::BigEndian
+        } else {
+            Endianness::LittleEndian
+        }
+    }
+}
+
+//
+// Pixel layout
+//
+
+impl PixelLayout {
+    /// Windows device independent bitmaps with 32 bits per pixel (`B`, `G`, `R`, `A` in memory)
+    pub const WINDOWS_DIB: PixelLayout = PixelLayout{ order: ChannelOrder::ARGB, endianness: Endianness::LittleEndian };
+
+    /// Wayland `WL_SHM_FORMAT_ARGB8888`
+    pub const WAYLAND_ARGB8888: PixelLayout = PixelLayout{ order: ChannelOrder::ARGB, endianness: Endianness::LittleEndian };
+
+    /// Wayland `WL_SHM_FORMAT_XRGB8888`
+    pub const WAYLAND_XRGB8888: PixelLayout = PixelLayout{ order: ChannelOrder::XRGB, endianness: Endianness::LittleEndian };
+
+    /// Create a layout from a channel order and a byte order
+    pub fn new(order: ChannelOrder, endianness: Endianness) -> Self {
+        PixelLayout{ order, endianness }
+    }
+
+    /// Cairo `CAIRO_FORMAT_ARGB32`, `ARGB` in the native byte order
+    pub fn cairo_argb32() -> Self {
+        PixelLayout{ order: ChannelOrder::ARGB, endianness: Endianness::native() }
+    }
+
+    /// Pack a color into a `u32` value in the channel order of the layout
+    pub fn pack(&self, c: DigitalRGBAColor) -> u32 {
+        let [r, g, b, a] = self.order.shifts();
+        let alpha = if self.order.has_alpha() { c.a } else { 0xff };
+
+        (c.r as u32) << r | (c.g as u32) << g | (c.b as u32) << b | (alpha as u32) << a
+    }
+
+    /// Unpack a `u32` value in the channel order of the layout, colors without alpha channel are opaque
+    pub fn unpack(&self, v: u32) -> DigitalRGBAColor {
+        let [r, g, b, a] = self.order.shifts();
+
+        DigitalRGBAColor{
+            r: (v >> r & 0xff) as u8,
+            g: (v >> g & 0xff) as u8,
+            b: (v >> b & 0xff) as u8,
+            a: if self.order.has_alpha() { (v >> a & 0xff) as u8 } else { 255 }
+        }
+    }
+
+    /// Pack a color into the four bytes it occupies in memory
+    pub fn pack_bytes(&self, c: DigitalRGBAColor) -> [u8; 4] {
+        let v = self.pack(c);
+
+        match self.endianness {
+            Endianness::BigEndian => v.to_be_bytes(),
+            Endianness::LittleEndian => v.to_le_bytes()
+        }
+    }
+
+    /// Unpack a color from the four bytes it occupies in memory
+    pub fn unpack_bytes(&self, bytes: [u8; 4]) -> DigitalRGBAColor {
+        let v = match self.endianness {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes)
+        };
+
+        self.unpack(v)
+    }
+
+    /// Pack a slice of colors into `u32` values, `output` must be at least as long as `colors`
+    pub fn pack_slice(&self, colors: &[DigitalRGBAColor], output: &mut [u32]) -> Result<(), ColorConversionError> {
+        if output.len() < colors.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        for (o, c) in output.iter_mut().zip(colors) {
+            *o = self.pack(*c);
+        }
+
+        Ok(())
+    }
+
+    /// Unpack a slice of `u32` values, `output` must be at least as long as `values`
+    pub fn unpack_slice(&self, values: &[u32], output: &mut [DigitalRGBAColor]) -> Result<(), ColorConversionError> {
+        if output.len() < values.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        for (o, v) in output.iter_mut().zip(values) {
+            *o = self.unpack(*v);
+        }
+
+        Ok(())
+    }
+
+    /// Pack a slice of colors into a byte buffer of at least four bytes per color
+    pub fn pack_byte_slice(&self, colors: &[DigitalRGBAColor], output: &mut [u8]) -> Result<(), ColorConversionError> {
+        if output.len() < colors.len() * 4 {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        for (o, c) in output.chunks_exact_mut(4).zip(colors) {
+            o.copy_from_slice(&self.pack_bytes(*c));
+        }
+
+        Ok(())
+    }
+
+    /// Unpack a byte buffer with four bytes per color, `output` must hold `bytes.len() / 4` colors
+    ///
+    /// Trailing bytes that don't form a complete pixel are ignored.
+    pub fn unpack_byte_slice(&self, bytes: &[u8], output: &mut [DigitalRGBAColor]) -> Result<(), ColorConversionError> {
+        if output.len() < bytes.len() / 4 {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        for (o, b) in output.iter_mut().zip(bytes.chunks_exact(4)) {
+            *o = self.unpack_bytes([b[0], b[1], b[2], b[3]]);
+        }
+
+        Ok(())
+    }
+
+    /// Unpack a byte buffer with four bytes per color into a new vector
+    pub fn unpack_byte_vec(&self, bytes: &[u8]) -> Vec<DigitalRGBAColor> {
+        bytes.chunks_exact(4).map(|b| self.unpack_bytes([b[0], b[1], b[2], b[3]])).collect()
+    }
+
+    /// Pack colors into a new byte vector with four bytes per color
+    pub fn pack_byte_vec(&self, colors: &[DigitalRGBAColor]) -> Vec<u8> {
+        colors.iter().flat_map(|c| self.pack_bytes(*c)).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const COLOR: DigitalRGBAColor = DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 };
+
+    #[rustfmt::skip]
+    static PACKED: [(ChannelOrder, u32); 8] = [
+        (ChannelOrder::RGBA, 0x11223344),
+        (ChannelOrder::ARGB, 0x44112233),
+        (ChannelOrder::BGRA, 0x33221144),
+        (ChannelOrder::ABGR, 0x44332211),
+        (ChannelOrder::RGBX, 0x112233ff),
+        (ChannelOrder::BGRX, 0x332211ff),
+        (ChannelOrder::XRGB, 0xff112233),
+        (ChannelOrder::XBGR, 0xff332211),
+    ];
+
+    #[test]
+    fn pack_u32() {
+        for (order, packed) in PACKED.iter() {
+            let layout = PixelLayout::new(*order, Endianness::BigEndian);
+            assert_eq!(*packed, layout.pack(COLOR), "{:?}", order);
+
+            let expected = if order.has_alpha() { COLOR } else { DigitalRGBAColor{ a: 255, ..COLOR } };
+            assert_eq!(expected, layout.unpack(*packed), "{:?}", order);
+        }
+    }
+
+    #[test]
+    fn default_matches_u32() {
+        assert_eq!(u32::from(COLOR), PixelLayout::default().pack(COLOR));
+    }
+
+    #[test]
+    fn pack_bytes() {
+        assert_eq!([0x33, 0x22, 0x11, 0x44], PixelLayout::WINDOWS_DIB.pack_bytes(COLOR));
+        assert_eq!([0x44, 0x11, 0x22, 0x33], PixelLayout::new(ChannelOrder::ARGB, Endianness::BigEndian).pack_bytes(COLOR));
+        assert_eq!([0x33, 0x22, 0x11, 0xff], PixelLayout::WAYLAND_XRGB8888.pack_bytes(COLOR));
+        assert_eq!(COLOR, PixelLayout::WAYLAND_ARGB8888.unpack_bytes([0x33, 0x22, 0x11, 0x44]));
+        assert_eq!(PixelLayout::cairo_argb32().pack(COLOR).to_ne_bytes(), PixelLayout::cairo_argb32().pack_bytes(COLOR));
+    }
+
+    #[test]
+    fn pack_slices() {
+        let layout = PixelLayout::new(ChannelOrder::BGRA, Endianness::LittleEndian);
+        let colors = [COLOR, DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 4 }];
+
+        let mut bytes = [0u8; 8];
+        layout.pack_byte_slice(&colors, &mut bytes).unwrap();
+        assert_eq!([0x44, 0x11, 0x22, 0x33, 4, 1, 2, 3], bytes);
+        assert_eq!(bytes.to_vec(), layout.pack_byte_vec(&colors));
+
+        let mut output = [COLOR; 2];
+        layout.unpack_byte_slice(&bytes, &mut output).unwrap();
+        assert_eq!(colors, output);
+        assert_eq!(colors.to_vec(), layout.unpack_byte_vec(&bytes));
+
+        let mut values = [0u32; 2];
+        layout.pack_slice(&colors, &mut values).unwrap();
+        assert_eq!([0x33221144, 0x03020104], values);
+        layout.unpack_slice(&values, &mut output).unwrap();
+        assert_eq!(colors, output);
+    }
+
+    #[test]
+    fn pack_slices_too_small() {
+        let layout = PixelLayout::default();
+        let colors = [COLOR; 2];
+
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), layout.pack_byte_slice(&colors, &mut [0u8; 7]));
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), layout.pack_slice(&colors, &mut [0u32; 1]));
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), layout.unpack_byte_slice(&[0u8; 8], &mut [COLOR; 1]));
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), layout.unpack_slice(&[0u32; 2], &mut [COLOR; 1]));
+    }
+}
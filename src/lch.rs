@@ -0,0 +1,105 @@
+use crate::*;
+
+//
+// CIE Lab to LCh
+//
+
+impl From<CIELabColor> for CIELChColor {
+    fn from(f: CIELabColor) -> Self {
+        let (c, h) = to_polar(f.a, f.b);
+
+        CIELChColor{
+            l: f.l,
+            c,
+            h
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIEXYZColor> for CIELChColor {
+    fn from(f: CIEXYZColor) -> Self {
+        CIELChColor::from(CIELabColor::from(f))
+    }
+}
+
+/// Convert rectangular chromaticity coordinates to chroma and hue angle in degrees
+#[inline]
+pub(crate) fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    (a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0))
+}
+
+/// Convert chroma and hue angle in degrees to rectangular chromaticity coordinates
+#[inline]
+pub(crate) fn from_polar(c: f32, h: f32) -> (f32, f32) {
+    let (sin, cos) = h.to_radians().sin_cos();
+    (c * cos, c * sin)
+}
+
+//
+// RGB to LCh
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<DigitalRGBColor> for CIELChColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        CIELChColor::from(CIELabColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<NormalizedRGBColor> for CIELChColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        CIELChColor::from(CIELabColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl CIELabConvertible for CIELChColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for CIELChColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn lab_2_lch() {
+        let lab = CIELabColor{ l: 50.0, a: 0.0, b: -20.0 };
+        let lch = CIELChColor::from(lab);
+        assert_eq!(CIELChColor{ l: 50.0, c: 20.0, h: 270.0 }, lch);
+    }
+
+    #[test]
+    fn lch_2_lab() {
+        let lch = CIELChColor{ l: 53.240784, c: 104.55, h: 40.0 };
+        let lab = CIELabColor::from(lch);
+        assert!((lab.a - 80.0899).abs() < 1e-3, "{:?}", lab);
+        assert!((lab.b - 67.2037).abs() < 1e-3, "{:?}", lab);
+    }
+
+    #[test]
+    fn lab_2_lch_roundtrip() {
+        for &(a, b) in [(10.0, 10.0), (-30.0, 5.0), (-1.0, -80.0), (60.0, -20.0)].iter() {
+            let lab = CIELabColor{ l: 40.0, a, b };
+            let back = CIELabColor::from(CIELChColor::from(lab));
+            assert!((back.a - a).abs() < 1e-4, "{:?} != {:?}", back, lab);
+            assert!((back.b - b).abs() < 1e-4, "{:?} != {:?}", back, lab);
+        }
+    }
+}
@@ -1,30 +1,126 @@
 //! `grapho-color` describes the color primitives that are used for computer graphics.
-//! It contains color conversion functionality and will get SIMD acceleration in the future to be the most effective color-library.
+//! It contains color conversion functionality with SIMD acceleration to be the most effective color-library.
 //! 
 //! Currently the following color types are implemented:
 //! 
 //! - Normalized Grayscale (component values from 0.0 - 1.0)
 //! - Normalized RGB (component values from `0.0` to `1.0`)
 //! - Normalized RGBA (component values from `0.0` to `1.0`)
+//! - Linear RGB (linear light sRGB, component values from `0.0` to `1.0`)
 //! - Digital Grayscale (1 byte)
 //! - Digital RGB (1 byte per channel)
 //! - Digital RGBA (1 byte per channel)
+//! - Packed RGB565, RGB555, RGBA4444, RGBA5551 and RGB10A2
+//! - Normalized and Digital premultiplied alpha RGBA
 //! - Normalized YCbCr (component values: Y: `0.0` to `1.0`, Cb/Cr: `-0.5` to `0.5`)
 //! - Digital YCbCr (1 byte per channel)
+//! - Digital Grayscale, RGB, RGBA and YCbCr with 16 bit per channel (YCbCr also with 10 or 12 bit values)
 //! - CIE Lab (float components)
 //! - CIE XYZ (float components)
+//! - CIE LCh(ab), Luv and LCh(uv) (float components)
+//! - Grayscale, YCbCr, CIE Lab and CIE XYZ with alpha (normalized and digital variants)
+//! - Oklab and Oklch (float components)
+//! - Normalized HSL and HSV (component values from `0.0` to `1.0`, hue is a fraction of the full circle)
+//! - Digital HSL and HSV (1 byte per channel)
+//! - Normalized YCgCo (component values: Y: `0.0` to `1.0`, Cg/Co: `-0.5` to `0.5`)
+//! - Digital YCgCo (1 byte per channel)
+//! - Digital YCoCg-R (lossless integer transform, `i16` per channel)
 //! 
 //! Conversion from all color types into all others is implemented by implementing the `From`-trait.
 //! 
-//! Additionally there are `*Convertible`-traits for all color types which additionally implement functionality to convert iterators or `Vec`-Arrays. These will be implemented with SIMD in the future to provide the best performance possible.
+//! Additionally there are `*Convertible`-traits for all color types which additionally implement functionality to convert iterators or `Vec`-Arrays. The hot paths (RGB to and from YCbCr, grayscale, XYZ and Lab and RGBA premultiplication) use SSE2, AVX2 or NEON, selected at runtime, with a portable fallback.
+//! 
+//! The generic types `NormalizedRGB<T>`, `NormalizedRGBA<T>`, `NormalizedGrayscale<T>`, `NormalizedYCbCr<T>`,
+//! `CIEXYZ<T>` and `CIELab<T>` can be used with `f32`, `f64` or the half-float type `F16` as component type,
+//! their conversions are calculated with `f64` precision.
+//! 
+//! Wide gamut RGB working spaces (Display P3, Adobe RGB, Rec.2020, ProPhoto or custom primaries) are described
+//! by `RGBColorSpace` which converts to and from `CIEXYZColor`.
+//! 
+//! RGBA colors can be composited with the Porter-Duff operators in `CompositeOperator` and the blend modes
+//! in `BlendMode`.
+//! 
+//! Colors with alpha component can be flattened onto an opaque background with the `Flatten`-trait before
+//! they are converted into a color type without alpha component.
+//! 
+//! Color differences (ΔE*76, ΔE*94, CIEDE2000 and CMC l:c) can be calculated for all types that convert
+//! into `CIELabColor` with the `ColorDifference`-trait.
+//! 
+//! 32 bit pixels in other channel orders (ARGB, BGRA, ABGR, RGBX, ...) and byte orders can be packed and
+//! unpacked with `PixelLayout`.
+//! 
+//! Planar (`I420`, `YV12`), semi-planar (`NV12`, `NV21`) and packed 4:2:2 (`YUYV`, `UYVY`) frames with
+//! arbitrary row strides can be converted to interleaved RGB or RGBA buffers and back with `PlanarFrame`,
+//! the chroma is up- and downsampled as selected by `ChromaUpsampling` and `ChromaDownsampling`.
+//! 
+//! With the optional `rayon` feature all batch conversions get `par_`-prefixed variants that convert
+//! chunks of the input on multiple threads and produce the same output as the sequential functions.
+//! 
+//! Digital RGB and YCbCr colors can be converted with libjpeg style fixed-point arithmetic
+//! (`from_rgb_fixed`, `from_ycbcr_fixed`), the batch conversions select it with `YCbCrArithmetic`.
+//! 
+//! The sRGB transfer function is available with lookup tables for `u8` values (`srgb_to_linear_u8`,
+//! `linear_to_srgb_u8`). The exact decoding table is used by the batch conversions from `DigitalRGBColor`
+//! to XYZ and Lab, the approximate encoding table only by `convert_vec_rgb_with`/`convert_slice_rgb_with`
+//! of XYZ and Lab colors with `TransferPrecision::Fast`.
+//! 
+//! All `*Convertible`-traits can also convert slices into caller provided buffers (`convert_slice_*`) and,
+//! for color types of the same size, in place (`convert_in_place_*`) without allocating.
+//! 
+//! Byte buffers can be reinterpreted as slices of colors and back without copying with the `PlainColor`-trait.
 //! 
 //! To make integration of the color types easy with existing software there are `From`-traits for importing a color from `Vec<u8>` and `u32` types.
 
 #[derive(Debug, PartialEq)]
 pub enum ColorConversionError {
-    BufferTooSmall
+    BufferTooSmall,
+    /// the length of a byte buffer is not a multiple of the size of the color type or the sizes of the
+    /// color types of an in-place conversion differ
+    InvalidBufferLength,
+    /// a byte buffer is not aligned for the color type
+    UnalignedBuffer,
+    /// the number of planes doesn't match the planar format or a stride is shorter than a row
+    InvalidPlanes
+}
+
+mod cast;
+
+/// Color types with a fixed memory layout that can be reinterpreted as bytes without copying
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` or `#[repr(transparent)]`, must not contain padding and must be
+/// valid for every bit pattern.
+pub unsafe trait PlainColor: Copy + 'static {
+    /// Reinterpret a slice of colors as bytes
+    fn as_bytes(items: &[Self]) -> &[u8] {
+        cast::as_bytes(items)
+    }
+
+    /// Reinterpret a mutable slice of colors as bytes
+    fn as_bytes_mut(items: &mut [Self]) -> &mut [u8] {
+        cast::as_bytes_mut(items)
+    }
+
+    /// Reinterpret bytes as a slice of colors
+    ///
+    /// The buffer length has to be a multiple of the size of the color type and the buffer has to be
+    /// aligned for the color type.
+    fn from_bytes(bytes: &[u8]) -> Result<&[Self], ColorConversionError> {
+        cast::from_bytes(bytes)
+    }
+
+    /// Reinterpret mutable bytes as a mutable slice of colors
+    fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], ColorConversionError> {
+        cast::from_bytes_mut(bytes)
+    }
 }
 
+mod simd;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
 mod grayscale;
 
 /// Types marked with this trait will be convertible to `DigitalGrayscaleColor`
@@ -34,10 +130,52 @@ pub trait GrayscaleConvertible: From<DigitalGrayscaleColor> {
 
     /// Create an iterator that yields `DigitalGrayscaleColor` values for all input values
     fn convert_iter_grayscale(items: Box<dyn Iterator<Item = Self>>) -> Box<dyn Iterator<Item = DigitalGrayscaleColor>>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalGrayscaleColor` values
+    fn flatten_vec_grayscale(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalGrayscaleColor>
+        where Self: Flatten, DigitalGrayscaleColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscaleColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_grayscale(items: &[Self], output: &mut [DigitalGrayscaleColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalGrayscaleColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscaleColor` values of the same size
+    fn convert_in_place_grayscale(items: &mut [Self]) -> Result<&mut [DigitalGrayscaleColor], ColorConversionError>
+        where Self: PlainColor, DigitalGrayscaleColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalGrayscaleColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_grayscale(items: Vec<Self>) -> Vec<DigitalGrayscaleColor>
+        where Self: Copy + Sync, DigitalGrayscaleColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_grayscale)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscaleColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_grayscale(items: &[Self], output: &mut [DigitalGrayscaleColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalGrayscaleColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_grayscale)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscaleColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_grayscale(items: &mut [Self]) -> Result<&mut [DigitalGrayscaleColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalGrayscaleColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_grayscale)
+    }
 }
 
 /// Grayscale color type that is based on `u8`-components
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct DigitalGrayscaleColor {
     /// red component
     pub v: u8,
@@ -45,6 +183,7 @@ pub struct DigitalGrayscaleColor {
 
 /// Grayscale color type with normalized color values (float-components)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct NormalizedGrayscaleColor {
     /// red component
     pub v: f32,
@@ -59,11 +198,53 @@ pub trait RGBConvertible: From<DigitalRGBColor> {
 
     /// Create an iterator that yields `DigitalRGBColor` values for all input values
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) -> Box<dyn Iterator<Item = DigitalRGBColor>>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalRGBColor` values
+    fn flatten_vec_rgb(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalRGBColor>
+        where Self: Flatten, DigitalRGBColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_rgb(items: &[Self], output: &mut [DigitalRGBColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalRGBColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBColor` values of the same size
+    fn convert_in_place_rgb(items: &mut [Self]) -> Result<&mut [DigitalRGBColor], ColorConversionError>
+        where Self: PlainColor, DigitalRGBColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalRGBColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor>
+        where Self: Copy + Sync, DigitalRGBColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_rgb)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_rgb(items: &[Self], output: &mut [DigitalRGBColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalRGBColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_rgb)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_rgb(items: &mut [Self]) -> Result<&mut [DigitalRGBColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalRGBColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_rgb)
+    }
 }
 
 
 /// RGB color type that is based on `u8`-components
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct DigitalRGBColor {
     /// red component
     pub r: u8,
@@ -75,6 +256,7 @@ pub struct DigitalRGBColor {
 
 /// RGB color type with normalized color values (float-components)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct NormalizedRGBColor {
     /// red component
     pub r: f32,
@@ -91,10 +273,45 @@ pub trait RGBAConvertible: From<DigitalRGBAColor> {
 
     /// Convert a vector of color values into a vector of `DigitalRGBAColor` values
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor>;
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBAColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_rgba(items: &[Self], output: &mut [DigitalRGBAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalRGBAColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBAColor` values of the same size
+    fn convert_in_place_rgba(items: &mut [Self]) -> Result<&mut [DigitalRGBAColor], ColorConversionError>
+        where Self: PlainColor, DigitalRGBAColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalRGBAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor>
+        where Self: Copy + Sync, DigitalRGBAColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_rgba)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_rgba(items: &[Self], output: &mut [DigitalRGBAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalRGBAColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_rgba)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBAColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_rgba(items: &mut [Self]) -> Result<&mut [DigitalRGBAColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalRGBAColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_rgba)
+    }
 }
 
 /// RGBA color type with `u8`-components
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct DigitalRGBAColor {
     /// red component
     pub r: u8,
@@ -108,6 +325,7 @@ pub struct DigitalRGBAColor {
 
 /// RGBA color type with normalized color values (float-components)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct NormalizedRGBAColor {
     /// red component
     pub r: f32,
@@ -119,6 +337,285 @@ pub struct NormalizedRGBAColor {
     pub a: f32
 }
 
+mod layout;
+
+/// Order of the channels of a 32 bit pixel, from the most significant to the least significant byte
+///
+/// `X` is an unused byte, it is written as `0xff` and ignored when reading.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ChannelOrder {
+    /// red, green, blue, alpha
+    #[default]
+    RGBA,
+    /// alpha, red, green, blue
+    ARGB,
+    /// blue, green, red, alpha
+    BGRA,
+    /// alpha, blue, green, red
+    ABGR,
+    /// red, green, blue, unused
+    RGBX,
+    /// blue, green, red, unused
+    BGRX,
+    /// unused, red, green, blue
+    XRGB,
+    /// unused, blue, green, red
+    XBGR
+}
+
+/// Byte order of a 32 bit pixel in memory
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Endianness {
+    /// most significant byte first
+    #[default]
+    BigEndian,
+    /// least significant byte first
+    LittleEndian
+}
+
+/// Memory layout of 32 bit pixels, used to pack and unpack `DigitalRGBAColor` values
+///
+/// The channel order describes the `u32` value, the endianness how it is stored in a byte buffer.
+/// E.g. Cairo `ARGB32` is `ARGB` in native endianness, Windows DIBs and Wayland `ARGB8888` are
+/// `ARGB` in little endian (`B`, `G`, `R`, `A` in memory).
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PixelLayout {
+    /// order of the channels in the `u32` value
+    pub order: ChannelOrder,
+    /// byte order of the `u32` value in memory
+    pub endianness: Endianness
+}
+
+mod premultiplied;
+
+/// Types marked with this trait will be convertible to `DigitalPremultipliedRGBAColor`
+pub trait PremultipliedRGBAConvertible: From<DigitalPremultipliedRGBAColor> {
+
+    /// Convert a vector of color values into a vector of `DigitalPremultipliedRGBAColor` values
+    fn convert_vec_premultiplied(items: Vec<Self>) -> Vec<DigitalPremultipliedRGBAColor>;
+
+    /// Convert a slice of color values into a buffer of `DigitalPremultipliedRGBAColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_premultiplied(items: &[Self], output: &mut [DigitalPremultipliedRGBAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalPremultipliedRGBAColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalPremultipliedRGBAColor` values of the same size
+    fn convert_in_place_premultiplied(items: &mut [Self]) -> Result<&mut [DigitalPremultipliedRGBAColor], ColorConversionError>
+        where Self: PlainColor, DigitalPremultipliedRGBAColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalPremultipliedRGBAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_premultiplied(items: Vec<Self>) -> Vec<DigitalPremultipliedRGBAColor>
+        where Self: Copy + Sync, DigitalPremultipliedRGBAColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_premultiplied)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalPremultipliedRGBAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_premultiplied(items: &[Self], output: &mut [DigitalPremultipliedRGBAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalPremultipliedRGBAColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_premultiplied)
+    }
+
+    /// Convert a slice of color values in place into `DigitalPremultipliedRGBAColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_premultiplied(items: &mut [Self]) -> Result<&mut [DigitalPremultipliedRGBAColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalPremultipliedRGBAColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_premultiplied)
+    }
+}
+
+/// RGBA color type with `u8`-components, the color components are premultiplied with alpha
+///
+/// Color components must not be greater than the alpha component, a fully transparent
+/// color is always transparent black.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalPremultipliedRGBAColor {
+    /// red component, multiplied with alpha
+    pub r: u8,
+    /// green component, multiplied with alpha
+    pub g: u8,
+    /// blue component, multiplied with alpha
+    pub b: u8,
+    /// alpha component, 0 is transparent
+    pub a: u8
+}
+
+/// RGBA color type with normalized color values (float-components), the color components
+/// are premultiplied with alpha
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedPremultipliedRGBAColor {
+    /// red component, multiplied with alpha
+    pub r: f32,
+    /// green component, multiplied with alpha
+    pub g: f32,
+    /// blue component, multiplied with alpha
+    pub b: f32,
+    /// alpha component, 0 is transparent
+    pub a: f32
+}
+
+/// Types marked with this trait carry an alpha component and can be flattened onto an
+/// opaque background color
+///
+/// The result of `flatten` can be converted to every color type without alpha component.
+pub trait Flatten: Copy {
+
+    /// RGB type the color is composited into, keeps the precision of the color type
+    type Output;
+
+    /// Composite the color onto an opaque background color
+    fn flatten_rgb(self, background: DigitalRGBColor) -> Self::Output;
+
+    /// Composite the color onto an opaque background color and convert the result
+    fn flatten<T: From<Self::Output>>(self, background: DigitalRGBColor) -> T {
+        T::from(self.flatten_rgb(background))
+    }
+}
+
+mod compositing;
+
+/// Porter-Duff compositing operators
+///
+/// See [Compositing and Blending Level 1](https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators)
+/// for a description of the operators.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum CompositeOperator {
+    /// no regions are enabled
+    Clear,
+    /// only the source will be present
+    Source,
+    /// only the destination will be present
+    Destination,
+    /// source is placed over the destination
+    #[default]
+    SourceOver,
+    /// destination is placed over the source
+    DestinationOver,
+    /// the source that overlaps the destination replaces the destination
+    SourceIn,
+    /// the destination that overlaps the source replaces the source
+    DestinationIn,
+    /// the source that does not overlap the destination replaces the destination
+    SourceOut,
+    /// the destination that does not overlap the source replaces the source
+    DestinationOut,
+    /// the source that overlaps the destination is composited with the destination
+    SourceAtop,
+    /// the destination that overlaps the source is composited with the source
+    DestinationAtop,
+    /// the non-overlapping regions of source and destination are combined
+    Xor,
+    /// source and destination are added (`plus-lighter`)
+    Lighter
+}
+
+/// Blend modes of the W3C compositing specification and PDF
+///
+/// See [Compositing and Blending Level 1](https://www.w3.org/TR/compositing-1/#blending)
+/// for a description of the blend modes.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum BlendMode {
+    /// the source color is used
+    #[default]
+    Normal,
+    /// source and backdrop are multiplied
+    Multiply,
+    /// complements of source and backdrop are multiplied
+    Screen,
+    /// multiply or screen depending on the backdrop
+    Overlay,
+    /// the darker of source and backdrop
+    Darken,
+    /// the lighter of source and backdrop
+    Lighten,
+    /// brightens the backdrop to reflect the source
+    ColorDodge,
+    /// darkens the backdrop to reflect the source
+    ColorBurn,
+    /// multiply or screen depending on the source
+    HardLight,
+    /// darkens or lightens depending on the source
+    SoftLight,
+    /// absolute difference of source and backdrop
+    Difference,
+    /// like `Difference` with lower contrast
+    Exclusion,
+    /// hue of the source with saturation and luminosity of the backdrop
+    Hue,
+    /// saturation of the source with hue and luminosity of the backdrop
+    Saturation,
+    /// hue and saturation of the source with luminosity of the backdrop
+    Color,
+    /// luminosity of the source with hue and saturation of the backdrop
+    Luminosity
+}
+
+mod linear;
+
+pub use linear::{srgb_to_linear, linear_to_srgb, srgb_to_linear_u8, linear_to_srgb_u8};
+
+/// Evaluation of the sRGB transfer function when batches of colors are encoded to `u8` values
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TransferPrecision {
+    /// lookup table, the results may differ by one from the exact encoding
+    Fast,
+    /// exact evaluation of the transfer function, identical to the `From` implementations and the `*Convertible`-traits
+    #[default]
+    Exact
+}
+
+/// RGB color type with linear light values (float-components)
+///
+/// Uses the sRGB primaries and white point like `NormalizedRGBColor` but without the sRGB
+/// transfer curve applied, so the component values are proportional to the light intensity.
+/// Use this type for blending, blurring or scaling colors.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct LinearRGBColor {
+    /// red component, range: 0.0 to 1.0
+    pub r: f32,
+    /// green component, range: 0.0 to 1.0
+    pub g: f32,
+    /// blue component, range: 0.0 to 1.0
+    pub b: f32
+}
+
+mod packed;
+
+/// RGB color packed into 16 bits, 5 bits red (most significant), 6 bits green and 5 bits blue
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct PackedRGB565Color(pub u16);
+
+/// RGB color packed into 16 bits, 1 unused bit (most significant), 5 bits red, 5 bits green and 5 bits blue
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct PackedRGB555Color(pub u16);
+
+/// RGBA color packed into 16 bits, 4 bits each for red (most significant), green, blue and alpha
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct PackedRGBA4444Color(pub u16);
+
+/// RGBA color packed into 16 bits, 5 bits each for red (most significant), green and blue and 1 bit alpha
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct PackedRGBA5551Color(pub u16);
+
+/// RGBA color packed into 32 bits, 10 bits each for red (least significant), green and blue and 2 bits alpha
+///
+/// This is the layout of `DXGI_FORMAT_R10G10B10A2_UNORM` and OpenGL `GL_UNSIGNED_INT_2_10_10_10_REV`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct PackedRGB10A2Color(pub u32);
+
 mod ycbcr;
 
 /// Types marked with this trait will be convertible to `DigitalYCbCrColor`
@@ -126,10 +623,52 @@ pub trait YCbCrConvertible: From<DigitalYCbCrColor> {
 
     /// Convert a vector of color values into a vector of `DigitalYCbCrColor` values
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalYCbCrColor` values
+    fn flatten_vec_ycbcr(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalYCbCrColor>
+        where Self: Flatten, DigitalYCbCrColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCrColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_ycbcr(items: &[Self], output: &mut [DigitalYCbCrColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalYCbCrColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCrColor` values of the same size
+    fn convert_in_place_ycbcr(items: &mut [Self]) -> Result<&mut [DigitalYCbCrColor], ColorConversionError>
+        where Self: PlainColor, DigitalYCbCrColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalYCbCrColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor>
+        where Self: Copy + Sync, DigitalYCbCrColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_ycbcr)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCrColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_ycbcr(items: &[Self], output: &mut [DigitalYCbCrColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalYCbCrColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_ycbcr)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCrColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_ycbcr(items: &mut [Self]) -> Result<&mut [DigitalYCbCrColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalYCbCrColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_ycbcr)
+    }
 }
 
 /// YUV color type with `u8`-components
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct DigitalYCbCrColor {
     /// y component (luminance)
     pub y: u8,
@@ -141,6 +680,7 @@ pub struct DigitalYCbCrColor {
 
 /// YUV color type with normalized values (float-components)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct NormalizedYCbCrColor {
     /// y component (luminance), unclipped minimum is 0.0, maximum 1.0
     pub y: f32,
@@ -150,13 +690,378 @@ pub struct NormalizedYCbCrColor {
     pub cr: f32
 }
 
+/// Matrix coefficients used for conversion between RGB and YCbCr
+///
+/// The `From`-trait implementations use the JFIF variant of `BT601`, use the
+/// `from_rgb`/`from_ycbcr` functions to select another matrix.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum YCbCrMatrix {
+    /// ITU-R BT.601 (SDTV and JFIF/JPEG)
+    #[default]
+    BT601,
+    /// ITU-R BT.709 (HDTV)
+    BT709,
+    /// ITU-R BT.2020 non-constant luminance (UHDTV)
+    BT2020,
+    /// SMPTE 240M (early HDTV)
+    SMPTE240M,
+    /// custom luma coefficients for red (`kr`) and blue (`kb`), green is derived
+    Custom {
+        /// luma coefficient of the red component
+        kr: f32,
+        /// luma coefficient of the blue component
+        kb: f32
+    }
+}
+
+/// Quantization range of digital YCbCr values
+///
+/// The `From`-trait implementations use the full range like JFIF does, video material
+/// usually is encoded in limited range.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum YCbCrRange {
+    /// full range (PC levels), all components use the complete value range
+    #[default]
+    Full,
+    /// limited range (studio swing, TV levels), 8 bit: Y: 16 to 235, Cb/Cr: 16 to 240,
+    /// 10 bit: Y: 64 to 940, Cb/Cr: 64 to 960
+    Limited
+}
+
+mod fixed;
+
+/// Arithmetic of the batch conversions between digital RGB and YCbCr colors
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum YCbCrArithmetic {
+    /// `f32` arithmetic, identical to `DigitalYCbCrColor::from_rgb` and `DigitalRGBColor::from_ycbcr`
+    #[default]
+    Float,
+    /// 16 bit fixed-point integer arithmetic like libjpeg, bit-exact with libjpeg for `BT601` in full range
+    FixedPoint
+}
+
+mod planar;
+
+/// Memory layout of chroma subsampled YCbCr frames
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum PlanarFormat {
+    /// 4:2:0, a Y plane followed by a Cb (U) and a Cr (V) plane
+    #[default]
+    I420,
+    /// 4:2:0, a Y plane followed by a Cr (V) and a Cb (U) plane
+    YV12,
+    /// 4:2:0, a Y plane followed by a plane of interleaved Cb and Cr samples
+    NV12,
+    /// 4:2:0, a Y plane followed by a plane of interleaved Cr and Cb samples
+    NV21,
+    /// 4:2:2 packed into a single plane, `Y0 Cb Y1 Cr`
+    YUYV,
+    /// 4:2:2 packed into a single plane, `Cb Y0 Cr Y1`
+    UYVY
+}
+
+/// Reconstruction of the missing chroma samples when reading a subsampled frame
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ChromaUpsampling {
+    /// repeat each chroma sample for all pixels of its block
+    #[default]
+    Nearest,
+    /// interpolate between the neighboring chroma samples (3/4 and 1/4 weights like libjpeg fancy upsampling),
+    /// the chroma samples are assumed to be centered between the luma samples
+    Bilinear
+}
+
+/// Reduction of the chroma samples of a block when writing a subsampled frame
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ChromaDownsampling {
+    /// average the chroma of all pixels of a block
+    #[default]
+    Average,
+    /// use the chroma of the top left pixel of a block
+    TopLeft
+}
+
+/// Dimensions and row strides of a planar, semi-planar or packed 4:2:2 YCbCr frame
+///
+/// The planes are passed to the conversion functions in memory order, e.g. `[y, v, u]` for `YV12`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PlanarFrame {
+    /// memory layout of the frame
+    pub format: PlanarFormat,
+    /// width in pixels
+    pub width: usize,
+    /// height in pixels
+    pub height: usize,
+    /// distance in bytes between the starts of two rows of each plane, entries of unused planes are ignored
+    pub strides: [usize; 3]
+}
+
+mod bit16;
+
+/// Types marked with this trait will be convertible to `DigitalRGB16Color`
+pub trait RGB16Convertible: From<DigitalRGB16Color> {
+
+    /// Convert a vector of color values into a vector of `DigitalRGB16Color` values
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color>;
+
+    /// Convert a slice of color values into a buffer of `DigitalRGB16Color` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_rgb16(items: &[Self], output: &mut [DigitalRGB16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalRGB16Color: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGB16Color` values of the same size
+    fn convert_in_place_rgb16(items: &mut [Self]) -> Result<&mut [DigitalRGB16Color], ColorConversionError>
+        where Self: PlainColor, DigitalRGB16Color: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalRGB16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color>
+        where Self: Copy + Sync, DigitalRGB16Color: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_rgb16)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalRGB16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_rgb16(items: &[Self], output: &mut [DigitalRGB16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalRGB16Color: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_rgb16)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGB16Color` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_rgb16(items: &mut [Self]) -> Result<&mut [DigitalRGB16Color], ColorConversionError>
+        where Self: PlainColor + Send, DigitalRGB16Color: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_rgb16)
+    }
+}
+
+/// Types marked with this trait will be convertible to `DigitalRGBA16Color`
+pub trait RGBA16Convertible: From<DigitalRGBA16Color> {
+
+    /// Convert a vector of color values into a vector of `DigitalRGBA16Color` values
+    fn convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color>;
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBA16Color` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_rgba16(items: &[Self], output: &mut [DigitalRGBA16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalRGBA16Color: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBA16Color` values of the same size
+    fn convert_in_place_rgba16(items: &mut [Self]) -> Result<&mut [DigitalRGBA16Color], ColorConversionError>
+        where Self: PlainColor, DigitalRGBA16Color: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalRGBA16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color>
+        where Self: Copy + Sync, DigitalRGBA16Color: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_rgba16)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalRGBA16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_rgba16(items: &[Self], output: &mut [DigitalRGBA16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalRGBA16Color: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_rgba16)
+    }
+
+    /// Convert a slice of color values in place into `DigitalRGBA16Color` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_rgba16(items: &mut [Self]) -> Result<&mut [DigitalRGBA16Color], ColorConversionError>
+        where Self: PlainColor + Send, DigitalRGBA16Color: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_rgba16)
+    }
+}
+
+/// Types marked with this trait will be convertible to `DigitalGrayscale16Color`
+pub trait Grayscale16Convertible: From<DigitalGrayscale16Color> {
+
+    /// Convert a vector of color values into a vector of `DigitalGrayscale16Color` values
+    fn convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color>;
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscale16Color` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_grayscale16(items: &[Self], output: &mut [DigitalGrayscale16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalGrayscale16Color: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscale16Color` values of the same size
+    fn convert_in_place_grayscale16(items: &mut [Self]) -> Result<&mut [DigitalGrayscale16Color], ColorConversionError>
+        where Self: PlainColor, DigitalGrayscale16Color: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalGrayscale16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_grayscale16(items: Vec<Self>) -> Vec<DigitalGrayscale16Color>
+        where Self: Copy + Sync, DigitalGrayscale16Color: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_grayscale16)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscale16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_grayscale16(items: &[Self], output: &mut [DigitalGrayscale16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalGrayscale16Color: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_grayscale16)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscale16Color` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_grayscale16(items: &mut [Self]) -> Result<&mut [DigitalGrayscale16Color], ColorConversionError>
+        where Self: PlainColor + Send, DigitalGrayscale16Color: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_grayscale16)
+    }
+}
+
+/// Types marked with this trait will be convertible to `DigitalYCbCr16Color`
+pub trait YCbCr16Convertible: From<DigitalYCbCr16Color> {
+
+    /// Convert a vector of color values into a vector of `DigitalYCbCr16Color` values
+    fn convert_vec_ycbcr16(items: Vec<Self>) -> Vec<DigitalYCbCr16Color>;
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCr16Color` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_ycbcr16(items: &[Self], output: &mut [DigitalYCbCr16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalYCbCr16Color: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCr16Color` values of the same size
+    fn convert_in_place_ycbcr16(items: &mut [Self]) -> Result<&mut [DigitalYCbCr16Color], ColorConversionError>
+        where Self: PlainColor, DigitalYCbCr16Color: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalYCbCr16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_ycbcr16(items: Vec<Self>) -> Vec<DigitalYCbCr16Color>
+        where Self: Copy + Sync, DigitalYCbCr16Color: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_ycbcr16)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCr16Color` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_ycbcr16(items: &[Self], output: &mut [DigitalYCbCr16Color]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalYCbCr16Color: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_ycbcr16)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCr16Color` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_ycbcr16(items: &mut [Self]) -> Result<&mut [DigitalYCbCr16Color], ColorConversionError>
+        where Self: PlainColor + Send, DigitalYCbCr16Color: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_ycbcr16)
+    }
+}
+
+/// RGB color type with `u16`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalRGB16Color {
+    /// red component
+    pub r: u16,
+    /// green component
+    pub g: u16,
+    /// blue component
+    pub b: u16
+}
+
+/// RGBA color type with `u16`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalRGBA16Color {
+    /// red component
+    pub r: u16,
+    /// green component
+    pub g: u16,
+    /// blue component
+    pub b: u16,
+    /// alpha component, 0 is transparent
+    pub a: u16
+}
+
+/// Grayscale color type with `u16`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalGrayscale16Color {
+    /// gray value
+    pub v: u16
+}
+
+/// YCbCr color type with `u16`-components
+///
+/// The `From`-trait implementations use the full 16 bit range, use the `from_normalized`/`from_digital16`
+/// functions for limited range or 10 and 12 bit video material.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalYCbCr16Color {
+    /// y component (luminance)
+    pub y: u16,
+    /// cb component (chrominance blue), half of the value range is zero chrominance
+    pub cb: u16,
+    /// cr component (chrominance red), half of the value range is zero chrominance
+    pub cr: u16
+}
+
 mod cie;
+mod matrix;
+mod whitepoint;
+mod colorspace;
 
 /// Types marked with this trait will be convertible to `CIELabColor`
 pub trait CIELabConvertible: From<CIELabColor> {
 
     /// Convert a vector of color values into a vector of `CIELabColor` values
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `CIELabColor` values
+    fn flatten_vec_lab(items: Vec<Self>, background: DigitalRGBColor) -> Vec<CIELabColor>
+        where Self: Flatten, CIELabColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELabColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_lab(items: &[Self], output: &mut [CIELabColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIELabColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIELabColor` values of the same size
+    fn convert_in_place_lab(items: &mut [Self]) -> Result<&mut [CIELabColor], ColorConversionError>
+        where Self: PlainColor, CIELabColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIELabColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor>
+        where Self: Copy + Sync, CIELabColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_lab)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELabColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_lab(items: &[Self], output: &mut [CIELabColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIELabColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_lab)
+    }
+
+    /// Convert a slice of color values in place into `CIELabColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_lab(items: &mut [Self]) -> Result<&mut [CIELabColor], ColorConversionError>
+        where Self: PlainColor + Send, CIELabColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_lab)
+    }
 }
 
 /// Types marked with this trait will be convertible to `CIEXYZColor`
@@ -164,12 +1069,135 @@ pub trait CIEXYZConvertible: From<CIEXYZColor> {
 
     /// Convert a vector of color values into a vector of `CIEXYZColor` values
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `CIEXYZColor` values
+    fn flatten_vec_xyz(items: Vec<Self>, background: DigitalRGBColor) -> Vec<CIEXYZColor>
+        where Self: Flatten, CIEXYZColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `CIEXYZColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_xyz(items: &[Self], output: &mut [CIEXYZColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIEXYZColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIEXYZColor` values of the same size
+    fn convert_in_place_xyz(items: &mut [Self]) -> Result<&mut [CIEXYZColor], ColorConversionError>
+        where Self: PlainColor, CIEXYZColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIEXYZColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor>
+        where Self: Copy + Sync, CIEXYZColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_xyz)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIEXYZColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_xyz(items: &[Self], output: &mut [CIEXYZColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIEXYZColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_xyz)
+    }
+
+    /// Convert a slice of color values in place into `CIEXYZColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_xyz(items: &mut [Self]) -> Result<&mut [CIEXYZColor], ColorConversionError>
+        where Self: PlainColor + Send, CIEXYZColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_xyz)
+    }
+}
+
+/// Reference white of an illuminant for the CIE 1931 2° standard observer
+///
+/// XYZ values produced by the RGB conversions of this crate are relative to `D65`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum WhitePoint {
+    /// incandescent light, tungsten filament
+    A,
+    /// horizon light, used for printing (ICC profile connection space)
+    D50,
+    /// mid-morning or mid-afternoon daylight
+    D55,
+    /// noon daylight, used for sRGB and television
+    #[default]
+    D65,
+    /// north sky daylight
+    D75,
+    /// equal energy
+    E,
+    /// cool white fluorescent
+    F2,
+    /// broadband daylight fluorescent
+    F7,
+    /// narrow band white fluorescent
+    F11,
+    /// custom white point given as xy chromaticity coordinates
+    Custom {
+        /// x chromaticity coordinate
+        x: f32,
+        /// y chromaticity coordinate
+        y: f32
+    }
+}
+
+/// Chromatic adaptation transform used to convert XYZ values between white points
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ChromaticAdaptation {
+    /// Bradford transform, used by ICC profiles
+    #[default]
+    Bradford,
+    /// Von Kries transform with the Hunt-Pointer-Estevez cone response
+    VonKries,
+    /// CIECAM02 transform
+    CAT02,
+    /// naive scaling of the XYZ values
+    XYZScaling
+}
+
+/// Transfer function (gamma curve) of a RGB color space
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TransferFunction {
+    /// no transfer function, values are linear light
+    Linear,
+    /// piecewise sRGB curve, also used by Display P3
+    SRGB,
+    /// pure power curve with the given gamma, e.g. `563.0 / 256.0` for Adobe RGB
+    Gamma(f32),
+    /// ITU-R BT.709 curve, also used by BT.2020
+    Rec709,
+    /// ProPhoto (ROMM) RGB curve, gamma 1.8 with a linear segment near black
+    ProPhoto
+}
+
+/// Description of a RGB working space
+///
+/// The conversion matrices to and from `CIEXYZColor` are derived from the primaries
+/// and the white point, XYZ values are adapted to `D65` with the Bradford transform if the
+/// color space uses another white point.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RGBColorSpace {
+    /// xy chromaticity coordinates of the red primary
+    pub red: (f32, f32),
+    /// xy chromaticity coordinates of the green primary
+    pub green: (f32, f32),
+    /// xy chromaticity coordinates of the blue primary
+    pub blue: (f32, f32),
+    /// reference white
+    pub white: WhitePoint,
+    /// transfer function that is applied to the linear light values
+    pub transfer: TransferFunction
 }
 
 /// CIE Lab color type
 ///
 /// For description of basic concepts read: [Lab Colorspace](http://www.colourphil.co.uk/lab_lch_colour_space.shtml)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct CIELabColor {
     /// luminance component, range: 0.0 to 100.0
     pub l: f32,
@@ -183,6 +1211,7 @@ pub struct CIELabColor {
 ///
 /// For description of basic concepts read: [XYZ Colorspace](https://www.colourphil.co.uk/xyz_colour_space.shtml)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct CIEXYZColor {
     /// x component (red tristimulus primary), range: 0.0 to approx. 1.5
     pub x: f32,
@@ -191,3 +1220,916 @@ pub struct CIEXYZColor {
     /// z component (blue tristimulus primary), range 0.0 to approx 2.0
     pub z: f32
 }
+
+mod component;
+mod generic;
+
+pub use component::F16;
+
+/// Component type of the generic normalized color types
+///
+/// Implemented for `f32`, `f64` and the software half-float `F16`, conversions of the generic
+/// color types are calculated with `f64` precision.
+pub trait Component: Copy + PartialEq + std::fmt::Debug {
+
+    /// Convert a `f64` value to the component type, rounding to the nearest representable value
+    fn from_f64(v: f64) -> Self;
+
+    /// Convert the component to `f64`
+    fn to_f64(self) -> f64;
+}
+
+/// RGB color type with normalized color values, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedRGB<T> {
+    /// red component
+    pub r: T,
+    /// green component
+    pub g: T,
+    /// blue component
+    pub b: T
+}
+
+/// RGBA color type with normalized color values, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedRGBA<T> {
+    /// red component
+    pub r: T,
+    /// green component
+    pub g: T,
+    /// blue component
+    pub b: T,
+    /// alpha component, 0 is transparent
+    pub a: T
+}
+
+/// Grayscale color type with normalized values, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedGrayscale<T> {
+    /// gray value, range: 0.0 to 1.0
+    pub v: T
+}
+
+/// YCbCr color type with normalized values, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedYCbCr<T> {
+    /// y component (luminance), range: 0.0 to 1.0
+    pub y: T,
+    /// cb component (chrominance blue), range: -0.5 to 0.5
+    pub cb: T,
+    /// cr component (chrominance red), range: -0.5 to 0.5
+    pub cr: T
+}
+
+/// CIE XYZ color type, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIEXYZ<T> {
+    /// x component (red tristimulus primary), range: 0.0 to approx. 1.5
+    pub x: T,
+    /// y component (green tristimulus primary), range 0.0 to approx. 1.5
+    pub y: T,
+    /// z component (blue tristimulus primary), range 0.0 to approx 2.0
+    pub z: T
+}
+
+/// CIE Lab color type, generic over the component type
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIELab<T> {
+    /// luminance component, range: 0.0 to 100.0
+    pub l: T,
+    /// a component, range: -100.0 to 100.0
+    pub a: T,
+    /// b component, range: -100.0 to 100.0
+    pub b: T
+}
+
+mod alpha;
+
+/// Types marked with this trait will be convertible to `DigitalGrayscaleAlphaColor`
+pub trait GrayscaleAlphaConvertible: From<DigitalGrayscaleAlphaColor> {
+
+    /// Convert a vector of color values into a vector of `DigitalGrayscaleAlphaColor` values
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor>;
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscaleAlphaColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_grayscale_alpha(items: &[Self], output: &mut [DigitalGrayscaleAlphaColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalGrayscaleAlphaColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscaleAlphaColor` values of the same size
+    fn convert_in_place_grayscale_alpha(items: &mut [Self]) -> Result<&mut [DigitalGrayscaleAlphaColor], ColorConversionError>
+        where Self: PlainColor, DigitalGrayscaleAlphaColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalGrayscaleAlphaColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor>
+        where Self: Copy + Sync, DigitalGrayscaleAlphaColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_grayscale_alpha)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalGrayscaleAlphaColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_grayscale_alpha(items: &[Self], output: &mut [DigitalGrayscaleAlphaColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalGrayscaleAlphaColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_grayscale_alpha)
+    }
+
+    /// Convert a slice of color values in place into `DigitalGrayscaleAlphaColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_grayscale_alpha(items: &mut [Self]) -> Result<&mut [DigitalGrayscaleAlphaColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalGrayscaleAlphaColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_grayscale_alpha)
+    }
+}
+
+/// Types marked with this trait will be convertible to `DigitalYCbCrAColor`
+pub trait YCbCrAConvertible: From<DigitalYCbCrAColor> {
+
+    /// Convert a vector of color values into a vector of `DigitalYCbCrAColor` values
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor>;
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCrAColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_ycbcra(items: &[Self], output: &mut [DigitalYCbCrAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalYCbCrAColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCrAColor` values of the same size
+    fn convert_in_place_ycbcra(items: &mut [Self]) -> Result<&mut [DigitalYCbCrAColor], ColorConversionError>
+        where Self: PlainColor, DigitalYCbCrAColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalYCbCrAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor>
+        where Self: Copy + Sync, DigitalYCbCrAColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_ycbcra)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCbCrAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_ycbcra(items: &[Self], output: &mut [DigitalYCbCrAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalYCbCrAColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_ycbcra)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCbCrAColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_ycbcra(items: &mut [Self]) -> Result<&mut [DigitalYCbCrAColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalYCbCrAColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_ycbcra)
+    }
+}
+
+/// Types marked with this trait will be convertible to `CIELabAColor`
+pub trait CIELabAConvertible: From<CIELabAColor> {
+
+    /// Convert a vector of color values into a vector of `CIELabAColor` values
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor>;
+
+    /// Convert a slice of color values into a buffer of `CIELabAColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_laba(items: &[Self], output: &mut [CIELabAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIELabAColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIELabAColor` values of the same size
+    fn convert_in_place_laba(items: &mut [Self]) -> Result<&mut [CIELabAColor], ColorConversionError>
+        where Self: PlainColor, CIELabAColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIELabAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor>
+        where Self: Copy + Sync, CIELabAColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_laba)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELabAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_laba(items: &[Self], output: &mut [CIELabAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIELabAColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_laba)
+    }
+
+    /// Convert a slice of color values in place into `CIELabAColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_laba(items: &mut [Self]) -> Result<&mut [CIELabAColor], ColorConversionError>
+        where Self: PlainColor + Send, CIELabAColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_laba)
+    }
+}
+
+/// Types marked with this trait will be convertible to `CIEXYZAColor`
+pub trait CIEXYZAConvertible: From<CIEXYZAColor> {
+
+    /// Convert a vector of color values into a vector of `CIEXYZAColor` values
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor>;
+
+    /// Convert a slice of color values into a buffer of `CIEXYZAColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_xyza(items: &[Self], output: &mut [CIEXYZAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIEXYZAColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIEXYZAColor` values of the same size
+    fn convert_in_place_xyza(items: &mut [Self]) -> Result<&mut [CIEXYZAColor], ColorConversionError>
+        where Self: PlainColor, CIEXYZAColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIEXYZAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor>
+        where Self: Copy + Sync, CIEXYZAColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_xyza)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIEXYZAColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_xyza(items: &[Self], output: &mut [CIEXYZAColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIEXYZAColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_xyza)
+    }
+
+    /// Convert a slice of color values in place into `CIEXYZAColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_xyza(items: &mut [Self]) -> Result<&mut [CIEXYZAColor], ColorConversionError>
+        where Self: PlainColor + Send, CIEXYZAColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_xyza)
+    }
+}
+
+/// Grayscale color type with alpha and `u8`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalGrayscaleAlphaColor {
+    /// gray value
+    pub v: u8,
+    /// alpha component, 0 is transparent
+    pub a: u8
+}
+
+/// Grayscale color type with alpha and normalized values (float-components)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedGrayscaleAlphaColor {
+    /// gray value, range: 0.0 to 1.0
+    pub v: f32,
+    /// alpha component, 0 is transparent
+    pub a: f32
+}
+
+/// YCbCr color type with alpha and `u8`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalYCbCrAColor {
+    /// y component (luminance)
+    pub y: u8,
+    /// cb component (chrominance blue), 128 is zero chrominance
+    pub cb: u8,
+    /// cr component (chrominance red), 128 is zero chrominance
+    pub cr: u8,
+    /// alpha component, 0 is transparent
+    pub a: u8
+}
+
+/// YCbCr color type with alpha and normalized values (float-components)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedYCbCrAColor {
+    /// y component (luminance), range: 0.0 to 1.0
+    pub y: f32,
+    /// cb component (chrominance blue), range: -0.5 to 0.5
+    pub cb: f32,
+    /// cr component (chrominance red), range: -0.5 to 0.5
+    pub cr: f32,
+    /// alpha component, 0 is transparent
+    pub a: f32
+}
+
+/// CIE Lab color type with alpha
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIELabAColor {
+    /// luminance component, range: 0.0 to 100.0
+    pub l: f32,
+    /// a component, range: -100.0 to 100.0
+    pub a: f32,
+    /// b component, range: -100.0 to 100.0
+    pub b: f32,
+    /// alpha component, range: 0.0 (transparent) to 1.0
+    pub alpha: f32
+}
+
+/// CIE XYZ color type with alpha
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIEXYZAColor {
+    /// x component (red tristimulus primary), range: 0.0 to approx. 1.5
+    pub x: f32,
+    /// y component (green tristimulus primary), range 0.0 to approx. 1.5
+    pub y: f32,
+    /// z component (blue tristimulus primary), range 0.0 to approx 2.0
+    pub z: f32,
+    /// alpha component, range: 0.0 (transparent) to 1.0
+    pub alpha: f32
+}
+
+mod lch;
+
+/// Types marked with this trait will be convertible to `CIELChColor`
+pub trait CIELChConvertible: From<CIELChColor> {
+
+    /// Convert a vector of color values into a vector of `CIELChColor` values
+    fn convert_vec_lch(items: Vec<Self>) -> Vec<CIELChColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `CIELChColor` values
+    fn flatten_vec_lch(items: Vec<Self>, background: DigitalRGBColor) -> Vec<CIELChColor>
+        where Self: Flatten, CIELChColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELChColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_lch(items: &[Self], output: &mut [CIELChColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIELChColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIELChColor` values of the same size
+    fn convert_in_place_lch(items: &mut [Self]) -> Result<&mut [CIELChColor], ColorConversionError>
+        where Self: PlainColor, CIELChColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIELChColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_lch(items: Vec<Self>) -> Vec<CIELChColor>
+        where Self: Copy + Sync, CIELChColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_lch)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELChColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_lch(items: &[Self], output: &mut [CIELChColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIELChColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_lch)
+    }
+
+    /// Convert a slice of color values in place into `CIELChColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_lch(items: &mut [Self]) -> Result<&mut [CIELChColor], ColorConversionError>
+        where Self: PlainColor + Send, CIELChColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_lch)
+    }
+}
+
+/// CIE LCh(ab) color type, the polar representation of `CIELabColor`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIELChColor {
+    /// luminance component, range: 0.0 to 100.0
+    pub l: f32,
+    /// chroma component, range: 0.0 to approx. 150.0
+    pub c: f32,
+    /// hue angle in degrees, range: 0.0 to 360.0
+    pub h: f32
+}
+
+mod luv;
+
+/// Types marked with this trait will be convertible to `CIELuvColor`
+pub trait CIELuvConvertible: From<CIELuvColor> {
+
+    /// Convert a vector of color values into a vector of `CIELuvColor` values
+    fn convert_vec_luv(items: Vec<Self>) -> Vec<CIELuvColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `CIELuvColor` values
+    fn flatten_vec_luv(items: Vec<Self>, background: DigitalRGBColor) -> Vec<CIELuvColor>
+        where Self: Flatten, CIELuvColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELuvColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_luv(items: &[Self], output: &mut [CIELuvColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIELuvColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIELuvColor` values of the same size
+    fn convert_in_place_luv(items: &mut [Self]) -> Result<&mut [CIELuvColor], ColorConversionError>
+        where Self: PlainColor, CIELuvColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIELuvColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_luv(items: Vec<Self>) -> Vec<CIELuvColor>
+        where Self: Copy + Sync, CIELuvColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_luv)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELuvColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_luv(items: &[Self], output: &mut [CIELuvColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIELuvColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_luv)
+    }
+
+    /// Convert a slice of color values in place into `CIELuvColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_luv(items: &mut [Self]) -> Result<&mut [CIELuvColor], ColorConversionError>
+        where Self: PlainColor + Send, CIELuvColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_luv)
+    }
+}
+
+/// Types marked with this trait will be convertible to `CIELChuvColor`
+pub trait CIELChuvConvertible: From<CIELChuvColor> {
+
+    /// Convert a vector of color values into a vector of `CIELChuvColor` values
+    fn convert_vec_lchuv(items: Vec<Self>) -> Vec<CIELChuvColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `CIELChuvColor` values
+    fn flatten_vec_lchuv(items: Vec<Self>, background: DigitalRGBColor) -> Vec<CIELChuvColor>
+        where Self: Flatten, CIELChuvColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELChuvColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_lchuv(items: &[Self], output: &mut [CIELChuvColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, CIELChuvColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `CIELChuvColor` values of the same size
+    fn convert_in_place_lchuv(items: &mut [Self]) -> Result<&mut [CIELChuvColor], ColorConversionError>
+        where Self: PlainColor, CIELChuvColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `CIELChuvColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_lchuv(items: Vec<Self>) -> Vec<CIELChuvColor>
+        where Self: Copy + Sync, CIELChuvColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_lchuv)
+    }
+
+    /// Convert a slice of color values into a buffer of `CIELChuvColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_lchuv(items: &[Self], output: &mut [CIELChuvColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, CIELChuvColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_lchuv)
+    }
+
+    /// Convert a slice of color values in place into `CIELChuvColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_lchuv(items: &mut [Self]) -> Result<&mut [CIELChuvColor], ColorConversionError>
+        where Self: PlainColor + Send, CIELChuvColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_lchuv)
+    }
+}
+
+/// CIE Luv color type
+///
+/// For description of basic concepts read: [Luv Colorspace](http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIELuvColor {
+    /// luminance component, range: 0.0 to 100.0
+    pub l: f32,
+    /// u component, range: approx. -100.0 to 180.0
+    pub u: f32,
+    /// v component, range: approx. -135.0 to 110.0
+    pub v: f32
+}
+
+/// CIE LCh(uv) color type, the polar representation of `CIELuvColor`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct CIELChuvColor {
+    /// luminance component, range: 0.0 to 100.0
+    pub l: f32,
+    /// chroma component, range: 0.0 to approx. 180.0
+    pub c: f32,
+    /// hue angle in degrees, range: 0.0 to 360.0
+    pub h: f32
+}
+
+mod oklab;
+
+/// Types marked with this trait will be convertible to `OklabColor`
+pub trait OklabConvertible: From<OklabColor> {
+
+    /// Convert a vector of color values into a vector of `OklabColor` values
+    fn convert_vec_oklab(items: Vec<Self>) -> Vec<OklabColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `OklabColor` values
+    fn flatten_vec_oklab(items: Vec<Self>, background: DigitalRGBColor) -> Vec<OklabColor>
+        where Self: Flatten, OklabColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `OklabColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_oklab(items: &[Self], output: &mut [OklabColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, OklabColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `OklabColor` values of the same size
+    fn convert_in_place_oklab(items: &mut [Self]) -> Result<&mut [OklabColor], ColorConversionError>
+        where Self: PlainColor, OklabColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `OklabColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_oklab(items: Vec<Self>) -> Vec<OklabColor>
+        where Self: Copy + Sync, OklabColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_oklab)
+    }
+
+    /// Convert a slice of color values into a buffer of `OklabColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_oklab(items: &[Self], output: &mut [OklabColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, OklabColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_oklab)
+    }
+
+    /// Convert a slice of color values in place into `OklabColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_oklab(items: &mut [Self]) -> Result<&mut [OklabColor], ColorConversionError>
+        where Self: PlainColor + Send, OklabColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_oklab)
+    }
+}
+
+/// Types marked with this trait will be convertible to `OklchColor`
+pub trait OklchConvertible: From<OklchColor> {
+
+    /// Convert a vector of color values into a vector of `OklchColor` values
+    fn convert_vec_oklch(items: Vec<Self>) -> Vec<OklchColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `OklchColor` values
+    fn flatten_vec_oklch(items: Vec<Self>, background: DigitalRGBColor) -> Vec<OklchColor>
+        where Self: Flatten, OklchColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `OklchColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_oklch(items: &[Self], output: &mut [OklchColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, OklchColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `OklchColor` values of the same size
+    fn convert_in_place_oklch(items: &mut [Self]) -> Result<&mut [OklchColor], ColorConversionError>
+        where Self: PlainColor, OklchColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `OklchColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_oklch(items: Vec<Self>) -> Vec<OklchColor>
+        where Self: Copy + Sync, OklchColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_oklch)
+    }
+
+    /// Convert a slice of color values into a buffer of `OklchColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_oklch(items: &[Self], output: &mut [OklchColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, OklchColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_oklch)
+    }
+
+    /// Convert a slice of color values in place into `OklchColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_oklch(items: &mut [Self]) -> Result<&mut [OklchColor], ColorConversionError>
+        where Self: PlainColor + Send, OklchColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_oklch)
+    }
+}
+
+/// Oklab perceptual color type
+///
+/// For description of basic concepts read: [Oklab Colorspace](https://bottosson.github.io/posts/oklab/)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct OklabColor {
+    /// perceived lightness, range: 0.0 to 1.0
+    pub l: f32,
+    /// green/red component, range: approx. -0.4 to 0.4
+    pub a: f32,
+    /// blue/yellow component, range: approx. -0.4 to 0.4
+    pub b: f32
+}
+
+/// Oklch color type, the polar representation of `OklabColor`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct OklchColor {
+    /// perceived lightness, range: 0.0 to 1.0
+    pub l: f32,
+    /// chroma component, range: 0.0 to approx. 0.4
+    pub c: f32,
+    /// hue angle in degrees, range: 0.0 to 360.0
+    pub h: f32
+}
+
+mod hsl;
+
+/// Types marked with this trait will be convertible to `DigitalHSLColor`
+pub trait HSLConvertible: From<DigitalHSLColor> {
+    /// Convert a vector of color values into a vector of `DigitalHSLColor` values
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor>;
+
+    /// Create an iterator that yields `DigitalHSLColor` values for all input values
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) -> Box<dyn Iterator<Item = DigitalHSLColor>>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalHSLColor` values
+    fn flatten_vec_hsl(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalHSLColor>
+        where Self: Flatten, DigitalHSLColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalHSLColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_hsl(items: &[Self], output: &mut [DigitalHSLColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalHSLColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalHSLColor` values of the same size
+    fn convert_in_place_hsl(items: &mut [Self]) -> Result<&mut [DigitalHSLColor], ColorConversionError>
+        where Self: PlainColor, DigitalHSLColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalHSLColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor>
+        where Self: Copy + Sync, DigitalHSLColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_hsl)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalHSLColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_hsl(items: &[Self], output: &mut [DigitalHSLColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalHSLColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_hsl)
+    }
+
+    /// Convert a slice of color values in place into `DigitalHSLColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_hsl(items: &mut [Self]) -> Result<&mut [DigitalHSLColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalHSLColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_hsl)
+    }
+}
+
+/// HSL color type with `u8`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalHSLColor {
+    /// hue component, the full circle is mapped to 0 - 255 (0 is red, 85 is green, 171 is blue)
+    pub h: u8,
+    /// saturation component
+    pub s: u8,
+    /// lightness component
+    pub l: u8
+}
+
+/// HSL color type with normalized values (float-components)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedHSLColor {
+    /// hue component, fraction of a full circle, range: 0.0 to 1.0 (0.0 is red)
+    pub h: f32,
+    /// saturation component, range: 0.0 to 1.0
+    pub s: f32,
+    /// lightness component, range: 0.0 to 1.0
+    pub l: f32
+}
+
+mod hsv;
+
+/// Types marked with this trait will be convertible to `DigitalHSVColor`
+pub trait HSVConvertible: From<DigitalHSVColor> {
+    /// Convert a vector of color values into a vector of `DigitalHSVColor` values
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor>;
+
+    /// Create an iterator that yields `DigitalHSVColor` values for all input values
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) -> Box<dyn Iterator<Item = DigitalHSVColor>>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalHSVColor` values
+    fn flatten_vec_hsv(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalHSVColor>
+        where Self: Flatten, DigitalHSVColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalHSVColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_hsv(items: &[Self], output: &mut [DigitalHSVColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalHSVColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalHSVColor` values of the same size
+    fn convert_in_place_hsv(items: &mut [Self]) -> Result<&mut [DigitalHSVColor], ColorConversionError>
+        where Self: PlainColor, DigitalHSVColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalHSVColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor>
+        where Self: Copy + Sync, DigitalHSVColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_hsv)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalHSVColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_hsv(items: &[Self], output: &mut [DigitalHSVColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalHSVColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_hsv)
+    }
+
+    /// Convert a slice of color values in place into `DigitalHSVColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_hsv(items: &mut [Self]) -> Result<&mut [DigitalHSVColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalHSVColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_hsv)
+    }
+}
+
+/// HSV color type with `u8`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalHSVColor {
+    /// hue component, the full circle is mapped to 0 - 255 (0 is red, 85 is green, 171 is blue)
+    pub h: u8,
+    /// saturation component
+    pub s: u8,
+    /// value component
+    pub v: u8
+}
+
+/// HSV color type with normalized values (float-components)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedHSVColor {
+    /// hue component, fraction of a full circle, range: 0.0 to 1.0 (0.0 is red)
+    pub h: f32,
+    /// saturation component, range: 0.0 to 1.0
+    pub s: f32,
+    /// value component, range: 0.0 to 1.0
+    pub v: f32
+}
+
+mod ycgco;
+
+/// Types marked with this trait will be convertible to `DigitalYCgCoColor`
+pub trait YCgCoConvertible: From<DigitalYCgCoColor> {
+
+    /// Convert a vector of color values into a vector of `DigitalYCgCoColor` values
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor>;
+
+    /// Flatten a vector of color values with alpha onto an opaque background color and
+    /// convert them into a vector of `DigitalYCgCoColor` values
+    fn flatten_vec_ycgco(items: Vec<Self>, background: DigitalRGBColor) -> Vec<DigitalYCgCoColor>
+        where Self: Flatten, DigitalYCgCoColor: From<<Self as Flatten>::Output> {
+        items.into_iter().map(|c| c.flatten(background)).collect()
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCgCoColor` values, `output` must be at least
+    /// as long as `items`
+    fn convert_slice_ycgco(items: &[Self], output: &mut [DigitalYCgCoColor]) -> Result<(), ColorConversionError>
+        where Self: Copy, DigitalYCgCoColor: From<Self> {
+        cast::convert_slice(items, output)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCgCoColor` values of the same size
+    fn convert_in_place_ycgco(items: &mut [Self]) -> Result<&mut [DigitalYCgCoColor], ColorConversionError>
+        where Self: PlainColor, DigitalYCgCoColor: PlainColor + From<Self> {
+        cast::convert_in_place(items)
+    }
+
+    /// Convert a vector of color values into a vector of `DigitalYCgCoColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor>
+        where Self: Copy + Sync, DigitalYCgCoColor: From<Self> {
+        parallel::convert_vec(items, Self::convert_slice_ycgco)
+    }
+
+    /// Convert a slice of color values into a buffer of `DigitalYCgCoColor` values on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_slice_ycgco(items: &[Self], output: &mut [DigitalYCgCoColor]) -> Result<(), ColorConversionError>
+        where Self: Copy + Sync, DigitalYCgCoColor: From<Self> {
+        parallel::convert_slice(items, output, Self::convert_slice_ycgco)
+    }
+
+    /// Convert a slice of color values in place into `DigitalYCgCoColor` values of the same size on multiple threads
+    #[cfg(feature = "rayon")]
+    fn par_convert_in_place_ycgco(items: &mut [Self]) -> Result<&mut [DigitalYCgCoColor], ColorConversionError>
+        where Self: PlainColor + Send, DigitalYCgCoColor: PlainColor + From<Self> {
+        parallel::convert_in_place(items, Self::convert_in_place_ycgco)
+    }
+}
+
+/// YCgCo color type with `u8`-components
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalYCgCoColor {
+    /// y component (luminance)
+    pub y: u8,
+    /// cg component (chrominance green), 128 is zero chrominance
+    pub cg: u8,
+    /// co component (chrominance orange), 128 is zero chrominance
+    pub co: u8
+}
+
+/// YCgCo color type with normalized values (float-components)
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct NormalizedYCgCoColor {
+    /// y component (luminance), range: 0.0 to 1.0
+    pub y: f32,
+    /// cg component (chrominance green), range: -0.5 to 0.5
+    pub cg: f32,
+    /// co component (chrominance orange), range: -0.5 to 0.5
+    pub co: f32
+}
+
+/// YCoCg-R color type, the lossless integer variant of YCgCo
+///
+/// Converting a `DigitalRGBColor` into this type and back is exactly reversible,
+/// as the chrominance components carry one bit more than the RGB components.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct DigitalYCoCgRColor {
+    /// y component (luminance), range: 0 to 255
+    pub y: i16,
+    /// co component (chrominance orange), range: -255 to 255
+    pub co: i16,
+    /// cg component (chrominance green), range: -255 to 255
+    pub cg: i16
+}
+
+mod difference;
+
+/// Application specific weighting factors of the ΔE*94 color difference
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum CIE94Application {
+    /// graphic arts: kL = 1, K1 = 0.045, K2 = 0.015
+    #[default]
+    GraphicArts,
+    /// textiles: kL = 2, K1 = 0.048, K2 = 0.014
+    Textiles
+}
+
+/// Color difference metrics, implemented for every type convertible to `CIELabColor`
+///
+/// The colors are converted to `CIELabColor` (relative to `D65`) and the difference is
+/// calculated with `f64` precision.
+pub trait ColorDifference: Copy {
+
+    /// CIE 1976 color difference (ΔE*76), the euclidean distance in Lab space
+    fn delta_e76(self, other: Self) -> f32;
+
+    /// CIE 1994 color difference (ΔE*94), `self` is the reference color
+    fn delta_e94(self, other: Self, application: CIE94Application) -> f32;
+
+    /// CIEDE2000 color difference (ΔE00) with the parametric factors kL = kC = kH = 1
+    fn delta_e2000(self, other: Self) -> f32;
+
+    /// CMC l:c color difference, `self` is the reference color, commonly used
+    /// with 2:1 (acceptability) or 1:1 (perceptibility)
+    fn delta_e_cmc(self, other: Self, lightness: f32, chroma: f32) -> f32;
+}
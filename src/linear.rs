@@ -0,0 +1,234 @@
+use crate::*;
+use crate::oklab::oklab_to_lms;
+use std::sync::OnceLock;
+
+/// Number of entries of the sRGB encoding lookup table
+const ENCODE_TABLE_SIZE: usize = 4096;
+
+//
+// sRGB transfer functions
+//
+
+/// Decode a gamma encoded sRGB component value (`0.0` to `1.0`) to linear light
+///
+/// using the [sRGB transfer function](http://www.brucelindbloom.com/index.html?Eqn_RGB_to_XYZ.html)
+#[inline]
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c > 0.04045 {
+        ((c + 0.055) / 1.055).powf(2.4)
+    } else {
+        c / 12.92
+    }
+}
+
+/// Encode a linear light component value (`0.0` to `1.0`) with the sRGB transfer function
+///
+/// using the [sRGB transfer function](http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_RGB.html)
+#[inline]
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c > 0.0031308 {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * c
+    }
+}
+
+/// `srgb_to_linear` with `f64` precision
+#[inline]
+pub(crate) fn srgb_to_linear_f64(c: f64) -> f64 {
+    if c > 0.04045 {
+        ((c + 0.055) / 1.055).powf(2.4)
+    } else {
+        c / 12.92
+    }
+}
+
+/// `linear_to_srgb` with `f64` precision
+#[inline]
+pub(crate) fn linear_to_srgb_f64(c: f64) -> f64 {
+    if c > 0.0031308 {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * c
+    }
+}
+
+fn decode_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 256];
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = srgb_to_linear(i as f32 / 255.0);
+        }
+        table
+    })
+}
+
+fn encode_table() -> &'static [u8; ENCODE_TABLE_SIZE] {
+    static TABLE: OnceLock<[u8; ENCODE_TABLE_SIZE]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0; ENCODE_TABLE_SIZE];
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = (linear_to_srgb(i as f32 / (ENCODE_TABLE_SIZE - 1) as f32) * 255.0).round() as u8;
+        }
+        table
+    })
+}
+
+/// Decode a gamma encoded `u8` sRGB component value to linear light with a lookup table
+///
+/// The result is identical to `srgb_to_linear(c as f32 / 255.0)`.
+#[inline]
+pub fn srgb_to_linear_u8(c: u8) -> f32 {
+    decode_table()[c as usize]
+}
+
+/// Encode a linear light component value (`0.0` to `1.0`) to a `u8` sRGB component value with a
+/// 4096 entry lookup table
+///
+/// Values outside of the range are clamped, the result may differ by one from the exact encoding.
+#[inline]
+pub fn linear_to_srgb_u8(c: f32) -> u8 {
+    let i = c.clamp(0.0, 1.0) * (ENCODE_TABLE_SIZE - 1) as f32 + 0.5;
+    encode_table()[i as usize]
+}
+
+//
+// RGB to linear RGB
+//
+
+impl From<NormalizedRGBColor> for LinearRGBColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        LinearRGBColor{
+            r: srgb_to_linear(f.r),
+            g: srgb_to_linear(f.g),
+            b: srgb_to_linear(f.b)
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for LinearRGBColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        LinearRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalRGBColor> for LinearRGBColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        LinearRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// CIE XYZ to linear RGB
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+#[allow(clippy::excessive_precision)]
+impl From<CIEXYZColor> for LinearRGBColor {
+    fn from(f: CIEXYZColor) -> Self {
+        LinearRGBColor{
+            r: f.x * 3.2404541621141054 - f.y * 1.5371385127977166 - f.z * 0.4985314095560162,
+            g: f.x * -0.9692660305051868 + f.y * 1.8760108454466942 + f.z * 0.04155601753034984,
+            b: f.x * 0.05564343095911469 - f.y * 0.20402591351675387 + f.z * 1.0572251882231791
+        }
+    }
+}
+
+//
+// Oklab to linear RGB
+//
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+#[allow(clippy::excessive_precision)]
+impl From<OklabColor> for LinearRGBColor {
+    fn from(f: OklabColor) -> Self {
+        let lms = oklab_to_lms(f);
+
+        LinearRGBColor{
+            r:  4.0767416621 * lms[0] - 3.3077115913 * lms[1] + 0.2309699292 * lms[2],
+            g: -1.2684380046 * lms[0] + 2.6097574011 * lms[1] - 0.3413193965 * lms[2],
+            b: -0.0041960863 * lms[0] - 0.7034186147 * lms[1] + 1.7076147010 * lms[2]
+        }
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<OklchColor> for LinearRGBColor {
+    fn from(f: OklchColor) -> Self {
+        LinearRGBColor::from(OklabColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl OklabConvertible for LinearRGBColor {
+    fn convert_vec_oklab(items: Vec<Self>) -> Vec<OklabColor> {
+        items.into_iter().map(OklabColor::from).collect()
+    }
+}
+
+impl OklchConvertible for LinearRGBColor {
+    fn convert_vec_oklch(items: Vec<Self>) -> Vec<OklchColor> {
+        items.into_iter().map(OklchColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn srgb_transfer_function() {
+        assert_eq!(0.0, srgb_to_linear(0.0));
+        assert_eq!(1.0, srgb_to_linear(1.0));
+        assert!((srgb_to_linear(0.5) - 0.21404114).abs() < 1e-6);
+        assert!((linear_to_srgb(0.21404114) - 0.5).abs() < 1e-6);
+        assert!((linear_to_srgb(1.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn srgb_lookup_tables() {
+        for v in 0..=255 {
+            assert_eq!(srgb_to_linear(v as f32 / 255.0), srgb_to_linear_u8(v));
+        }
+
+        for i in 0..=100_000 {
+            let c = i as f32 / 100_000.0;
+            let exact = (linear_to_srgb(c) * 255.0).round() as i32;
+            assert!((exact - linear_to_srgb_u8(c) as i32).abs() <= 1, "{}", c);
+        }
+
+        assert_eq!(0, linear_to_srgb_u8(-1.0));
+        assert_eq!(255, linear_to_srgb_u8(1.0));
+        assert_eq!(255, linear_to_srgb_u8(2.0));
+    }
+
+    #[test]
+    fn rgb_d_2_linear_rgb_2_rgb_d() {
+        for v in 0..=255 {
+            let rgb = DigitalRGBColor{ r: v, g: 255 - v, b: v / 3 };
+            assert_eq!(rgb, DigitalRGBColor::from(LinearRGBColor::from(rgb)));
+        }
+    }
+
+    #[test]
+    fn linear_rgb_2_xyz() {
+        let white = LinearRGBColor{ r: 1.0, g: 1.0, b: 1.0 };
+        let xyz = CIEXYZColor::from(white);
+        assert!((xyz.x - 0.95047).abs() < 1e-4);
+        assert!((xyz.y - 1.0).abs() < 1e-4);
+        assert!((xyz.z - 1.08883).abs() < 1e-4);
+
+        let back = LinearRGBColor::from(xyz);
+        assert!((back.r - 1.0).abs() < 1e-5);
+        assert!((back.g - 1.0).abs() < 1e-5);
+        assert!((back.b - 1.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::*;
+use crate::cie::{KAPPA, EPSILON};
+use crate::lch::{to_polar, from_polar};
+
+//
+// CIE XYZ to Luv
+//
+
+/// using a `D65` reference white and the [XYZ to Luv conversion](http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html)
+impl From<CIEXYZColor> for CIELuvColor {
+    fn from(f: CIEXYZColor) -> Self {
+        let w = WhitePoint::D65.xyz();
+        let (un, vn) = uv_prime(w);
+        let d = f.x + 15.0 * f.y + 3.0 * f.z;
+        if d <= 0.0 {
+            return CIELuvColor{ l: 0.0, u: 0.0, v: 0.0 };
+        }
+
+        let yr = f.y / w.y;
+        let l = if yr > EPSILON {
+            116.0 * yr.cbrt() - 16.0
+        } else {
+            KAPPA * yr
+        };
+
+        CIELuvColor{
+            l,
+            u: 13.0 * l * (4.0 * f.x / d - un),
+            v: 13.0 * l * (9.0 * f.y / d - vn)
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELabColor> for CIELuvColor {
+    fn from(f: CIELabColor) -> Self {
+        CIELuvColor::from(CIEXYZColor::from(f))
+    }
+}
+
+impl From<CIELChuvColor> for CIELuvColor {
+    fn from(f: CIELChuvColor) -> Self {
+        let (u, v) = from_polar(f.c, f.h);
+
+        CIELuvColor{
+            l: f.l,
+            u,
+            v
+        }
+    }
+}
+
+/// u' and v' chromaticity coordinates of a XYZ color
+#[inline]
+pub(crate) fn uv_prime(f: CIEXYZColor) -> (f32, f32) {
+    let d = f.x + 15.0 * f.y + 3.0 * f.z;
+    (4.0 * f.x / d, 9.0 * f.y / d)
+}
+
+//
+// CIE to LChuv
+//
+
+impl From<CIELuvColor> for CIELChuvColor {
+    fn from(f: CIELuvColor) -> Self {
+        let (c, h) = to_polar(f.u, f.v);
+
+        CIELChuvColor{
+            l: f.l,
+            c,
+            h
+        }
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIEXYZColor> for CIELChuvColor {
+    fn from(f: CIEXYZColor) -> Self {
+        CIELChuvColor::from(CIELuvColor::from(f))
+    }
+}
+
+/// using a `D65` reference white
+impl From<CIELabColor> for CIELChuvColor {
+    fn from(f: CIELabColor) -> Self {
+        CIELChuvColor::from(CIELuvColor::from(f))
+    }
+}
+
+//
+// RGB to Luv
+//
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<DigitalRGBColor> for CIELuvColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        CIELuvColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<NormalizedRGBColor> for CIELuvColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        CIELuvColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<DigitalRGBColor> for CIELChuvColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        CIELChuvColor::from(CIEXYZColor::from(f))
+    }
+}
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+impl From<NormalizedRGBColor> for CIELChuvColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        CIELChuvColor::from(CIEXYZColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl CIELChuvConvertible for CIELuvColor {
+    fn convert_vec_lchuv(items: Vec<Self>) -> Vec<CIELChuvColor> {
+        items.into_iter().map(CIELChuvColor::from).collect()
+    }
+}
+
+impl CIELuvConvertible for CIELChuvColor {
+    fn convert_vec_luv(items: Vec<Self>) -> Vec<CIELuvColor> {
+        items.into_iter().map(CIELuvColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for CIELuvColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIELabConvertible for CIELChuvColor {
+    fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
+        items.into_iter().map(CIELabColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for CIELuvColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for CIELChuvColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn xyz_2_luv_white() {
+        let luv = CIELuvColor::from(WhitePoint::D65.xyz());
+        assert!((luv.l - 100.0).abs() < 1e-4);
+        assert!(luv.u.abs() < 1e-4);
+        assert!(luv.v.abs() < 1e-4);
+    }
+
+    #[test]
+    fn xyz_2_luv_black() {
+        let luv = CIELuvColor::from(CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 });
+        assert_eq!(CIELuvColor{ l: 0.0, u: 0.0, v: 0.0 }, luv);
+        assert_eq!(CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 }, CIEXYZColor::from(luv));
+    }
+
+    #[test]
+    fn rgb_2_luv() {
+        // sRGB red, see http://www.brucelindbloom.com/index.html?ColorCalculator.html
+        let luv = CIELuvColor::from(CIEXYZColor::from(DigitalRGBColor{ r: 255, g: 0, b: 0 }));
+        assert!((luv.l - 53.2408).abs() < 0.01, "{:?}", luv);
+        assert!((luv.u - 175.0151).abs() < 0.01, "{:?}", luv);
+        assert!((luv.v - 37.7564).abs() < 0.01, "{:?}", luv);
+    }
+
+    #[test]
+    fn luv_2_xyz_roundtrip() {
+        let xyz = CIEXYZColor{ x: 0.2, y: 0.3, z: 0.6 };
+        let back = CIEXYZColor::from(CIELChuvColor::from(xyz));
+        assert!((back.x - xyz.x).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+        assert!((back.y - xyz.y).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+        assert!((back.z - xyz.z).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+    }
+}
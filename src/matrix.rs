@@ -0,0 +1,68 @@
+//! Small 3x3 matrix helpers used to derive the conversion matrices of the CIE types
+
+pub(crate) type Matrix3 = [[f32; 3]; 3];
+
+pub(crate) const IDENTITY: Matrix3 = [
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0]
+];
+
+/// Multiply two matrices
+pub(crate) fn mul(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    result
+}
+
+/// Multiply a matrix with a column vector
+#[inline]
+pub(crate) fn mul_vec(m: &Matrix3, v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2]
+    ]
+}
+
+/// Build a diagonal matrix
+pub(crate) fn diagonal(v: [f32; 3]) -> Matrix3 {
+    [
+        [v[0], 0.0, 0.0],
+        [0.0, v[1], 0.0],
+        [0.0, 0.0, v[2]]
+    ]
+}
+
+/// Invert a matrix, the matrix has to be invertible
+pub(crate) fn invert(m: &Matrix3) -> Matrix3 {
+    // calculate in double precision, the results are used as conversion matrices
+    let m = m.map(|row| row.map(f64::from));
+
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det = m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02;
+
+    [
+        [
+            (c00 / det) as f32,
+            ((m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det) as f32,
+            ((m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det) as f32
+        ],
+        [
+            (c01 / det) as f32,
+            ((m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det) as f32,
+            ((m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det) as f32
+        ],
+        [
+            (c02 / det) as f32,
+            ((m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det) as f32,
+            ((m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det) as f32
+        ]
+    ]
+}
@@ -0,0 +1,222 @@
+use crate::*;
+use crate::lch::{to_polar, from_polar};
+
+//
+// Linear RGB to Oklab
+//
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+#[allow(clippy::excessive_precision)]
+impl From<LinearRGBColor> for OklabColor {
+    fn from(f: LinearRGBColor) -> Self {
+        lms_to_oklab([
+            0.4122214708 * f.r + 0.5363325363 * f.g + 0.0514459929 * f.b,
+            0.2119034982 * f.r + 0.6806995451 * f.g + 0.1073969566 * f.b,
+            0.0883024619 * f.r + 0.2817188376 * f.g + 0.6299787005 * f.b
+        ])
+    }
+}
+
+//
+// CIE XYZ to Oklab
+//
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+#[allow(clippy::excessive_precision)]
+impl From<CIEXYZColor> for OklabColor {
+    fn from(f: CIEXYZColor) -> Self {
+        lms_to_oklab([
+            0.8189330101 * f.x + 0.3618667424 * f.y - 0.1288597137 * f.z,
+            0.0329845436 * f.x + 0.9293118715 * f.y + 0.0361456387 * f.z,
+            0.0482003018 * f.x + 0.2643662691 * f.y + 0.6338517070 * f.z
+        ])
+    }
+}
+
+/// Convert linear LMS cone responses to Oklab
+#[inline]
+#[allow(clippy::excessive_precision)]
+fn lms_to_oklab(lms: [f32; 3]) -> OklabColor {
+    let l = lms[0].cbrt();
+    let m = lms[1].cbrt();
+    let s = lms[2].cbrt();
+
+    OklabColor{
+        l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s
+    }
+}
+
+/// Convert Oklab to linear LMS cone responses
+#[inline]
+#[allow(clippy::excessive_precision)]
+pub(crate) fn oklab_to_lms(f: OklabColor) -> [f32; 3] {
+    let l = f.l + 0.3963377774 * f.a + 0.2158037573 * f.b;
+    let m = f.l - 0.1055613458 * f.a - 0.0638541728 * f.b;
+    let s = f.l - 0.0894841775 * f.a - 1.2914855480 * f.b;
+
+    [l * l * l, m * m * m, s * s * s]
+}
+
+//
+// Oklch to Oklab
+//
+
+impl From<OklchColor> for OklabColor {
+    fn from(f: OklchColor) -> Self {
+        let (a, b) = from_polar(f.c, f.h);
+
+        OklabColor{
+            l: f.l,
+            a,
+            b
+        }
+    }
+}
+
+//
+// Oklab to Oklch
+//
+
+impl From<OklabColor> for OklchColor {
+    fn from(f: OklabColor) -> Self {
+        let (c, h) = to_polar(f.a, f.b);
+
+        OklchColor{
+            l: f.l,
+            c,
+            h
+        }
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<LinearRGBColor> for OklchColor {
+    fn from(f: LinearRGBColor) -> Self {
+        OklchColor::from(OklabColor::from(f))
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<CIEXYZColor> for OklchColor {
+    fn from(f: CIEXYZColor) -> Self {
+        OklchColor::from(OklabColor::from(f))
+    }
+}
+
+//
+// RGB to Oklab
+//
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<DigitalRGBColor> for OklabColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        OklabColor::from(LinearRGBColor::from(f))
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<NormalizedRGBColor> for OklabColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        OklabColor::from(LinearRGBColor::from(f))
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<DigitalRGBColor> for OklchColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        OklchColor::from(LinearRGBColor::from(f))
+    }
+}
+
+/// using the [Oklab conversion](https://bottosson.github.io/posts/oklab/)
+impl From<NormalizedRGBColor> for OklchColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        OklchColor::from(LinearRGBColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl OklabConvertible for OklchColor {
+    fn convert_vec_oklab(items: Vec<Self>) -> Vec<OklabColor> {
+        items.into_iter().map(OklabColor::from).collect()
+    }
+}
+
+impl OklchConvertible for OklabColor {
+    fn convert_vec_oklch(items: Vec<Self>) -> Vec<OklchColor> {
+        items.into_iter().map(OklchColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for OklabColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl CIEXYZConvertible for OklchColor {
+    fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn assert_oklab_eq(a: OklabColor, b: OklabColor, tolerance: f32) {
+        assert!((a.l - b.l).abs() < tolerance, "{:?} != {:?}", a, b);
+        assert!((a.a - b.a).abs() < tolerance, "{:?} != {:?}", a, b);
+        assert!((a.b - b.b).abs() < tolerance, "{:?} != {:?}", a, b);
+    }
+
+    #[rustfmt::skip]
+    static OKLAB_COLOURS: [(CIEXYZColor, OklabColor); 4] = [
+        (CIEXYZColor { x: 0.950, y: 1.000, z: 1.089 }, OklabColor { l: 1.000, a:  0.000, b:  0.000 }),
+        (CIEXYZColor { x: 1.000, y: 0.000, z: 0.000 }, OklabColor { l: 0.450, a:  1.236, b: -0.019 }),
+        (CIEXYZColor { x: 0.000, y: 1.000, z: 0.000 }, OklabColor { l: 0.922, a: -0.671, b:  0.263 }),
+        (CIEXYZColor { x: 0.000, y: 0.000, z: 1.000 }, OklabColor { l: 0.153, a: -1.415, b: -0.449 }),
+    ];
+
+    #[test]
+    fn xyz_2_oklab() {
+        for test in OKLAB_COLOURS.iter() {
+            assert_oklab_eq(test.1, OklabColor::from(test.0), 1e-3);
+        }
+    }
+
+    #[test]
+    fn linear_rgb_2_oklab() {
+        let red = LinearRGBColor{ r: 1.0, g: 0.0, b: 0.0 };
+        assert_oklab_eq(OklabColor{ l: 0.627955, a: 0.224863, b: 0.125846 }, OklabColor::from(red), 1e-5);
+        let white = LinearRGBColor{ r: 1.0, g: 1.0, b: 1.0 };
+        assert_oklab_eq(OklabColor{ l: 1.0, a: 0.0, b: 0.0 }, OklabColor::from(white), 1e-4);
+    }
+
+    #[test]
+    fn oklab_2_linear_rgb_roundtrip() {
+        let rgb = LinearRGBColor{ r: 0.2, g: 0.5, b: 0.9 };
+        let back = LinearRGBColor::from(OklchColor::from(rgb));
+        assert!((back.r - rgb.r).abs() < 1e-5, "{:?} != {:?}", back, rgb);
+        assert!((back.g - rgb.g).abs() < 1e-5, "{:?} != {:?}", back, rgb);
+        assert!((back.b - rgb.b).abs() < 1e-5, "{:?} != {:?}", back, rgb);
+    }
+
+    #[test]
+    fn oklab_2_xyz_roundtrip() {
+        let xyz = CIEXYZColor{ x: 0.4, y: 0.3, z: 0.2 };
+        let back = CIEXYZColor::from(OklabColor::from(xyz));
+        assert!((back.x - xyz.x).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+        assert!((back.y - xyz.y).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+        assert!((back.z - xyz.z).abs() < 1e-5, "{:?} != {:?}", back, xyz);
+    }
+}
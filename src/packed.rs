@@ -0,0 +1,263 @@
+use crate::*;
+
+/// Expand a value of `bits` bits to `target` bits by replicating the bit pattern
+#[inline]
+pub(crate) fn replicate(v: u32, bits: u32, target: u32) -> u32 {
+    let mut r = v << (target - bits);
+    let mut filled = bits;
+    while filled < target {
+        r |= r >> filled;
+        filled *= 2;
+    }
+    r
+}
+
+/// Reduce a value in the range `0..=max` to `bits` bits with correct rounding
+#[inline]
+pub(crate) fn reduce(v: u32, max: u32, bits: u32) -> u32 {
+    (v * ((1 << bits) - 1) + max / 2) / max
+}
+
+/// Extract the field of `bits` bits at `shift` from a packed value
+#[inline]
+pub(crate) fn field(v: u32, shift: u32, bits: u32) -> u32 {
+    (v >> shift) & ((1 << bits) - 1)
+}
+
+//
+// RGB to packed RGB
+//
+
+impl From<DigitalRGBColor> for PackedRGB565Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        PackedRGB565Color(
+            (reduce(f.r as u32, 255, 5) << 11 |
+             reduce(f.g as u32, 255, 6) << 5 |
+             reduce(f.b as u32, 255, 5)) as u16
+        )
+    }
+}
+
+impl From<DigitalRGBColor> for PackedRGB555Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        PackedRGB555Color(
+            (reduce(f.r as u32, 255, 5) << 10 |
+             reduce(f.g as u32, 255, 5) << 5 |
+             reduce(f.b as u32, 255, 5)) as u16
+        )
+    }
+}
+
+impl From<DigitalRGBAColor> for PackedRGB565Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        PackedRGB565Color::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<DigitalRGBAColor> for PackedRGB555Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        PackedRGB555Color::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// RGBA to packed RGBA
+//
+
+impl From<DigitalRGBAColor> for PackedRGBA4444Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        PackedRGBA4444Color(
+            (reduce(f.r as u32, 255, 4) << 12 |
+             reduce(f.g as u32, 255, 4) << 8 |
+             reduce(f.b as u32, 255, 4) << 4 |
+             reduce(f.a as u32, 255, 4)) as u16
+        )
+    }
+}
+
+impl From<DigitalRGBAColor> for PackedRGBA5551Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        PackedRGBA5551Color(
+            (reduce(f.r as u32, 255, 5) << 11 |
+             reduce(f.g as u32, 255, 5) << 6 |
+             reduce(f.b as u32, 255, 5) << 1 |
+             reduce(f.a as u32, 255, 1)) as u16
+        )
+    }
+}
+
+impl From<DigitalRGBAColor> for PackedRGB10A2Color {
+    fn from(f: DigitalRGBAColor) -> Self {
+        PackedRGB10A2Color(
+            replicate(f.r as u32, 8, 10) |
+            replicate(f.g as u32, 8, 10) << 10 |
+            replicate(f.b as u32, 8, 10) << 20 |
+            reduce(f.a as u32, 255, 2) << 30
+        )
+    }
+}
+
+impl From<DigitalRGBA16Color> for PackedRGB10A2Color {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        PackedRGB10A2Color(
+            reduce(f.r as u32, 65535, 10) |
+            reduce(f.g as u32, 65535, 10) << 10 |
+            reduce(f.b as u32, 65535, 10) << 20 |
+            reduce(f.a as u32, 65535, 2) << 30
+        )
+    }
+}
+
+impl From<NormalizedRGBAColor> for PackedRGB10A2Color {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        let q = |c: f32, max: f32| (c * max).round().clamp(0.0, max) as u32;
+
+        PackedRGB10A2Color(
+            q(f.r, 1023.0) |
+            q(f.g, 1023.0) << 10 |
+            q(f.b, 1023.0) << 20 |
+            q(f.a, 3.0) << 30
+        )
+    }
+}
+
+//
+// RGB to packed RGBA
+//
+
+impl From<DigitalRGBColor> for PackedRGBA4444Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        PackedRGBA4444Color::from(DigitalRGBAColor::from(f))
+    }
+}
+
+impl From<DigitalRGBColor> for PackedRGBA5551Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        PackedRGBA5551Color::from(DigitalRGBAColor::from(f))
+    }
+}
+
+impl From<DigitalRGBColor> for PackedRGB10A2Color {
+    fn from(f: DigitalRGBColor) -> Self {
+        PackedRGB10A2Color::from(DigitalRGBAColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl RGBConvertible for PackedRGB565Color {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBConvertible for PackedRGB555Color {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBAConvertible for PackedRGBA4444Color {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for PackedRGBA5551Color {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for PackedRGB10A2Color {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use super::{replicate, reduce};
+
+    #[test]
+    fn bit_replication() {
+        assert_eq!(0b10000100, replicate(0b10000, 5, 8));
+        assert_eq!(0b11111111, replicate(0b11111, 5, 8));
+        assert_eq!(0b01000001, replicate(0b010000, 6, 8));
+        assert_eq!(0x77, replicate(0x7, 4, 8));
+        assert_eq!(0xff, replicate(1, 1, 8));
+        assert_eq!(0xaa, replicate(2, 2, 8));
+        assert_eq!(0xffff, replicate(1023, 10, 16));
+    }
+
+    #[test]
+    fn reduce_inverts_replicate() {
+        for bits in 1..=8 {
+            for v in 0..(1 << bits) {
+                assert_eq!(v, reduce(replicate(v, bits, 8), 255, bits), "{} bits: {}", bits, v);
+            }
+        }
+    }
+
+    #[test]
+    fn rgb565() {
+        let white = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+        assert_eq!(PackedRGB565Color(0xffff), PackedRGB565Color::from(white));
+        assert_eq!(white, DigitalRGBColor::from(PackedRGB565Color(0xffff)));
+
+        let red = PackedRGB565Color(0xf800);
+        assert_eq!(DigitalRGBColor{ r: 255, g: 0, b: 0 }, DigitalRGBColor::from(red));
+        assert_eq!(DigitalRGBColor{ r: 132, g: 134, b: 8 }, DigitalRGBColor::from(PackedRGB565Color(0x8421)));
+    }
+
+    #[test]
+    fn rgb555() {
+        let c = DigitalRGBColor{ r: 0, g: 255, b: 8 };
+        assert_eq!(PackedRGB555Color(0x03e1), PackedRGB555Color::from(c));
+        assert_eq!(c, DigitalRGBColor::from(PackedRGB555Color(0x03e1)));
+    }
+
+    #[test]
+    fn rgba4444_and_5551() {
+        let c = DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 };
+        assert_eq!(PackedRGBA4444Color(0x1234), PackedRGBA4444Color::from(c));
+        assert_eq!(c, DigitalRGBAColor::from(PackedRGBA4444Color(0x1234)));
+
+        let c = DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 200 };
+        assert_eq!(PackedRGBA5551Color(0xf83f), PackedRGBA5551Color::from(c));
+        assert_eq!(DigitalRGBAColor{ r: 255, g: 0, b: 255, a: 255 }, DigitalRGBAColor::from(PackedRGBA5551Color(0xf83f)));
+    }
+
+    #[test]
+    fn rgb10a2() {
+        let c = DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 255 };
+        let packed = PackedRGB10A2Color::from(c);
+        assert_eq!(0xc000_0000 | 0x3ff | 0x202 << 10, packed.0);
+        assert_eq!(c, DigitalRGBAColor::from(packed));
+
+        let c16 = DigitalRGBA16Color::from(packed);
+        assert_eq!(DigitalRGBA16Color{ r: 65535, g: 32928, b: 0, a: 65535 }, c16);
+        assert_eq!(packed, PackedRGB10A2Color::from(c16));
+
+        let n = NormalizedRGBAColor{ r: 1.0, g: 0.5, b: 0.0, a: 1.0 / 3.0 };
+        let packed = PackedRGB10A2Color::from(n);
+        assert_eq!(0x4000_0000 | 0x3ff | 512 << 10, packed.0);
+        let back = NormalizedRGBAColor::from(packed);
+        assert!((back.g - 0.5).abs() < 1.0 / 1023.0, "{:?}", back);
+    }
+}
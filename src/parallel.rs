@@ -0,0 +1,107 @@
+use crate::*;
+use rayon::prelude::*;
+
+/// Number of colors converted by one task, small enough for the source and destination of a chunk to
+/// stay in the cache
+const CHUNK: usize = 4096;
+
+//
+// Parallel conversions
+//
+
+pub(crate) fn convert_vec<S: Sync, D: PlainColor + Send>(
+    items: Vec<S>,
+    convert: impl Fn(&[S], &mut [D]) -> Result<(), ColorConversionError> + Sync
+) -> Vec<D> {
+    // every bit pattern is a valid `PlainColor`, the zeroed colors are all overwritten by `convert`
+    let mut output = vec![unsafe { std::mem::zeroed::<D>() }; items.len()];
+    items.par_chunks(CHUNK).zip(output.par_chunks_mut(CHUNK)).for_each(|(i, o)| {
+        convert(i, o).expect("the output has the length of the input")
+    });
+    output
+}
+
+pub(crate) fn convert_slice<S: Sync, D: Send>(
+    items: &[S],
+    output: &mut [D],
+    convert: impl Fn(&[S], &mut [D]) -> Result<(), ColorConversionError> + Sync
+) -> Result<(), ColorConversionError> {
+    if output.len() < items.len() {
+        return Err(ColorConversionError::BufferTooSmall);
+    }
+
+    items.par_chunks(CHUNK).zip(output.par_chunks_mut(CHUNK)).try_for_each(|(i, o)| convert(i, o))
+}
+
+pub(crate) fn convert_in_place<S: PlainColor + Send, D: PlainColor>(
+    items: &mut [S],
+    convert: fn(&mut [S]) -> Result<&mut [D], ColorConversionError>
+) -> Result<&mut [D], ColorConversionError> {
+    // report size and alignment errors like the sequential conversion, even for empty slices
+    convert(&mut [])?;
+    cast::from_bytes::<D>(cast::as_bytes(items))?;
+
+    items.par_chunks_mut(CHUNK).try_for_each(|c| convert(c).map(|_| ()))?;
+    cast::from_bytes_mut(cast::as_bytes_mut(items))
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn rgb_samples() -> Vec<DigitalRGBColor> {
+        (0..3 * super::CHUNK + 17).map(|i| DigitalRGBColor{ r: i as u8, g: (i / 7) as u8, b: (i / 131) as u8 }).collect()
+    }
+
+    #[test]
+    fn par_convert_vec() {
+        let items = rgb_samples();
+        assert_eq!(DigitalRGBColor::convert_vec_lab(items.clone()), DigitalRGBColor::par_convert_vec_lab(items.clone()));
+        assert_eq!(DigitalRGBColor::convert_vec_ycbcr(items.clone()), DigitalRGBColor::par_convert_vec_ycbcr(items.clone()));
+        assert_eq!(DigitalRGBColor::convert_vec_hsl(items.clone()), DigitalRGBColor::par_convert_vec_hsl(items.clone()));
+
+        let xyz = DigitalRGBColor::convert_vec_xyz(items);
+        assert_eq!(CIEXYZColor::convert_vec_rgb(xyz.clone()), CIEXYZColor::par_convert_vec_rgb(xyz.clone()));
+        assert_eq!(
+            CIEXYZColor::convert_vec_rgb_with(xyz.clone(), TransferPrecision::Exact),
+            CIEXYZColor::par_convert_vec_rgb_with(xyz, TransferPrecision::Exact)
+        );
+
+        assert_eq!(Vec::<CIELabColor>::new(), DigitalRGBColor::par_convert_vec_lab(Vec::new()));
+    }
+
+    #[test]
+    fn par_convert_slice() {
+        let items = rgb_samples();
+        let mut output = vec![DigitalGrayscaleColor{ v: 0 }; items.len() + 1];
+        DigitalRGBColor::par_convert_slice_grayscale(&items, &mut output).unwrap();
+        assert_eq!(DigitalRGBColor::convert_vec_grayscale(items.clone()), output[..items.len()]);
+        assert_eq!(DigitalGrayscaleColor{ v: 0 }, output[items.len()]);
+
+        let mut ycbcr = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; items.len()];
+        DigitalRGBColor::par_convert_slice_ycbcr_with(&items, &mut ycbcr, YCbCrMatrix::BT709, YCbCrRange::Limited, YCbCrArithmetic::FixedPoint).unwrap();
+        assert_eq!(DigitalRGBColor::convert_vec_ycbcr_with(items.clone(), YCbCrMatrix::BT709, YCbCrRange::Limited, YCbCrArithmetic::FixedPoint), ycbcr);
+        assert_eq!(
+            DigitalYCbCrColor::convert_vec_rgb_with(ycbcr.clone(), YCbCrMatrix::BT709, YCbCrRange::Limited, YCbCrArithmetic::FixedPoint),
+            DigitalYCbCrColor::par_convert_vec_rgb_with(ycbcr, YCbCrMatrix::BT709, YCbCrRange::Limited, YCbCrArithmetic::FixedPoint)
+        );
+
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), DigitalRGBColor::par_convert_slice_grayscale(&items, &mut output[..10]));
+    }
+
+    #[test]
+    fn par_convert_in_place() {
+        let items = rgb_samples();
+        let expected = DigitalRGBColor::convert_vec_ycbcr(items.clone());
+        let mut buffer = items.clone();
+        assert_eq!(expected, DigitalRGBColor::par_convert_in_place_ycbcr(&mut buffer).unwrap());
+
+        let mut buffer = items;
+        assert_eq!(Err(ColorConversionError::InvalidBufferLength), DigitalRGBColor::par_convert_in_place_rgba(&mut buffer));
+        assert_eq!(Err(ColorConversionError::InvalidBufferLength), DigitalRGBColor::par_convert_in_place_rgba(&mut []));
+    }
+}
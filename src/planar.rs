@@ -0,0 +1,436 @@
+use crate::*;
+use crate::fixed;
+
+//
+// Planar format
+//
+
+impl PlanarFormat {
+    /// Number of planes of the format
+    pub fn plane_count(self) -> usize {
+        match self {
+            PlanarFormat::I420 | PlanarFormat::YV12 => 3,
+            PlanarFormat::NV12 | PlanarFormat::NV21 => 2,
+            PlanarFormat::YUYV | PlanarFormat::UYVY => 1
+        }
+    }
+
+    /// Whether the chroma is subsampled vertically too (4:2:0)
+    #[inline]
+    fn vertical_subsampling(self) -> bool {
+        self.plane_count() > 1
+    }
+}
+
+/// Position of the chroma sample used to interpolate a pixel at `pos`, `c` is the nearest chroma sample
+#[inline]
+fn neighbor(pos: usize, c: usize, count: usize) -> usize {
+    if pos.is_multiple_of(2) {
+        c.saturating_sub(1)
+    } else {
+        (c + 1).min(count - 1)
+    }
+}
+
+//
+// Planar frame
+//
+
+impl PlanarFrame {
+    /// Create a frame without padding between the rows
+    pub fn new(format: PlanarFormat, width: usize, height: usize) -> Self {
+        let mut frame = PlanarFrame{ format, width, height, strides: [0; 3] };
+        frame.strides = frame.row_bytes();
+        frame
+    }
+
+    /// Sizes in bytes of the planes with the strides of the frame, entries of unused planes are 0
+    pub fn plane_sizes(&self) -> [usize; 3] {
+        let rows = self.rows();
+        [self.strides[0] * rows[0], self.strides[1] * rows[1], self.strides[2] * rows[2]]
+    }
+
+    /// Number of bytes of a row of each plane
+    fn row_bytes(&self) -> [usize; 3] {
+        let chroma_width = self.width.div_ceil(2);
+
+        match self.format {
+            PlanarFormat::I420 | PlanarFormat::YV12 => [self.width, chroma_width, chroma_width],
+            PlanarFormat::NV12 | PlanarFormat::NV21 => [self.width, chroma_width * 2, 0],
+            PlanarFormat::YUYV | PlanarFormat::UYVY => [chroma_width * 4, 0, 0]
+        }
+    }
+
+    /// Number of rows of each plane
+    fn rows(&self) -> [usize; 3] {
+        let chroma_height = self.height.div_ceil(2);
+
+        match self.format {
+            PlanarFormat::I420 | PlanarFormat::YV12 => [self.height, chroma_height, chroma_height],
+            PlanarFormat::NV12 | PlanarFormat::NV21 => [self.height, chroma_height, 0],
+            PlanarFormat::YUYV | PlanarFormat::UYVY => [self.height, 0, 0]
+        }
+    }
+
+    /// Check the number of planes, the strides and the plane lengths, the last row doesn't need padding
+    fn validate(&self, lengths: impl ExactSizeIterator<Item = usize>) -> Result<(), ColorConversionError> {
+        if lengths.len() != self.format.plane_count() {
+            return Err(ColorConversionError::InvalidPlanes);
+        }
+
+        let row_bytes = self.row_bytes();
+        let rows = self.rows();
+        for (i, len) in lengths.enumerate() {
+            if self.strides[i] < row_bytes[i] {
+                return Err(ColorConversionError::InvalidPlanes);
+            }
+            if rows[i] > 0 && len < self.strides[i] * (rows[i] - 1) + row_bytes[i] {
+                return Err(ColorConversionError::BufferTooSmall);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Byte offset of the luma sample of a pixel in the first plane
+    #[inline]
+    fn luma_offset(&self, x: usize, y: usize) -> usize {
+        let row = y * self.strides[0];
+
+        match self.format {
+            PlanarFormat::YUYV => row + x * 2,
+            PlanarFormat::UYVY => row + x * 2 + 1,
+            _ => row + x
+        }
+    }
+
+    /// Planes and byte offsets of the cb and cr samples of a chroma block
+    #[inline]
+    fn chroma_offsets(&self, cx: usize, cy: usize) -> [(usize, usize); 2] {
+        let [s0, s1, s2] = self.strides;
+
+        match self.format {
+            PlanarFormat::I420 => [(1, cy * s1 + cx), (2, cy * s2 + cx)],
+            PlanarFormat::YV12 => [(2, cy * s2 + cx), (1, cy * s1 + cx)],
+            PlanarFormat::NV12 => [(1, cy * s1 + cx * 2), (1, cy * s1 + cx * 2 + 1)],
+            PlanarFormat::NV21 => [(1, cy * s1 + cx * 2 + 1), (1, cy * s1 + cx * 2)],
+            PlanarFormat::YUYV => [(0, cy * s0 + cx * 4 + 1), (0, cy * s0 + cx * 4 + 3)],
+            PlanarFormat::UYVY => [(0, cy * s0 + cx * 4), (0, cy * s0 + cx * 4 + 2)]
+        }
+    }
+
+    /// Cb and cr of a pixel, reconstructed from the chroma samples with `upsampling`
+    fn chroma(&self, planes: &[&[u8]], x: usize, y: usize, upsampling: ChromaUpsampling) -> [u8; 2] {
+        let vertical = self.format.vertical_subsampling();
+        let sample = |cx: usize, cy: usize| {
+            let [(pb, ob), (pr, or)] = self.chroma_offsets(cx, cy);
+            [planes[pb][ob] as u32, planes[pr][or] as u32]
+        };
+
+        let cx = x / 2;
+        let cy = if vertical { y / 2 } else { y };
+
+        match upsampling {
+            ChromaUpsampling::Nearest => {
+                let [cb, cr] = sample(cx, cy);
+                [cb as u8, cr as u8]
+            },
+            ChromaUpsampling::Bilinear => {
+                let cx2 = neighbor(x, cx, self.width.div_ceil(2));
+                let near = sample(cx, cy);
+                let side = sample(cx2, cy);
+
+                if vertical {
+                    let cy2 = neighbor(y, cy, self.height.div_ceil(2));
+                    let far = sample(cx, cy2);
+                    let corner = sample(cx2, cy2);
+                    [0, 1].map(|i| ((9 * near[i] + 3 * side[i] + 3 * far[i] + corner[i] + 8) >> 4) as u8)
+                } else {
+                    [0, 1].map(|i| ((3 * near[i] + side[i] + 2) >> 2) as u8)
+                }
+            }
+        }
+    }
+
+    /// Convert the planes of the frame into an interleaved buffer of `width * height` colors
+    ///
+    /// The YCbCr values are quantized to `range` and converted with the coefficients of `matrix` and `arithmetic`.
+    pub fn convert_to_rgb<T>(&self, planes: &[&[u8]], output: &mut [T], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic, upsampling: ChromaUpsampling) -> Result<(), ColorConversionError>
+        where T: From<DigitalRGBColor> {
+        self.validate(planes.iter().map(|p| p.len()))?;
+        if output.len() < self.width * self.height {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        if self.width == 0 || self.height == 0 {
+            return Ok(());
+        }
+
+        let mut ycbcr = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; self.width];
+        let mut rgb = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; self.width];
+
+        for (y, out) in output.chunks_exact_mut(self.width).take(self.height).enumerate() {
+            for (x, c) in ycbcr.iter_mut().enumerate() {
+                let [cb, cr] = self.chroma(planes, x, y, upsampling);
+                *c = DigitalYCbCrColor{ y: planes[0][self.luma_offset(x, y)], cb, cr };
+            }
+
+            fixed::ycbcr_to_rgb(&ycbcr, &mut rgb, matrix, range, arithmetic);
+            for (o, c) in out.iter_mut().zip(&rgb) {
+                *o = T::from(*c);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Convert an interleaved buffer of `width * height` colors into the planes of the frame
+    ///
+    /// The YCbCr values are quantized to `range` and converted with the coefficients of `matrix` and `arithmetic`,
+    /// padding bytes between the rows are not written.
+    pub fn convert_from_rgb<T>(&self, colors: &[T], planes: &mut [&mut [u8]], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic, downsampling: ChromaDownsampling) -> Result<(), ColorConversionError>
+        where T: Copy, DigitalRGBColor: From<T> {
+        self.validate(planes.iter().map(|p| p.len()))?;
+        if colors.len() < self.width * self.height {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+        if self.width == 0 || self.height == 0 {
+            return Ok(());
+        }
+
+        let block_rows = if self.format.vertical_subsampling() { 2 } else { 1 };
+        let mut rgb = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; self.width * block_rows];
+        let mut ycbcr = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; self.width * block_rows];
+
+        for (cy, block) in colors[..self.width * self.height].chunks(self.width * block_rows).enumerate() {
+            let n = block.len();
+            for (o, c) in rgb.iter_mut().zip(block) {
+                *o = DigitalRGBColor::from(*c);
+            }
+            fixed::rgb_to_ycbcr(&rgb[..n], &mut ycbcr[..n], matrix, range, arithmetic);
+
+            for (r, row) in ycbcr[..n].chunks_exact(self.width).enumerate() {
+                let y = cy * block_rows + r;
+                for (x, c) in row.iter().enumerate() {
+                    planes[0][self.luma_offset(x, y)] = c.y;
+                }
+            }
+
+            for cx in 0..self.width.div_ceil(2) {
+                let [cb, cr] = match downsampling {
+                    ChromaDownsampling::TopLeft => [ycbcr[cx * 2].cb, ycbcr[cx * 2].cr],
+                    ChromaDownsampling::Average => {
+                        let (mut cb, mut cr, mut count) = (0, 0, 0);
+                        for row in ycbcr[..n].chunks_exact(self.width) {
+                            for c in &row[cx * 2..(cx * 2 + 2).min(self.width)] {
+                                cb += c.cb as u32;
+                                cr += c.cr as u32;
+                                count += 1;
+                            }
+                        }
+                        [((cb + count / 2) / count) as u8, ((cr + count / 2) / count) as u8]
+                    }
+                };
+
+                let [(pb, ob), (pr, or)] = self.chroma_offsets(cx, cy);
+                planes[pb][ob] = cb;
+                planes[pr][or] = cr;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FORMATS: [PlanarFormat; 6] = [
+        PlanarFormat::I420,
+        PlanarFormat::YV12,
+        PlanarFormat::NV12,
+        PlanarFormat::NV21,
+        PlanarFormat::YUYV,
+        PlanarFormat::UYVY
+    ];
+
+    fn allocate(frame: &PlanarFrame) -> Vec<Vec<u8>> {
+        frame.plane_sizes().iter().take(frame.format.plane_count()).map(|s| vec![0xaa; *s]).collect()
+    }
+
+    fn write(frame: &PlanarFrame, colors: &[DigitalRGBColor], planes: &mut [Vec<u8>], downsampling: ChromaDownsampling) {
+        let mut slices: Vec<&mut [u8]> = planes.iter_mut().map(|p| p.as_mut_slice()).collect();
+        frame.convert_from_rgb(colors, &mut slices, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::FixedPoint, downsampling).unwrap();
+    }
+
+    fn read<T: From<DigitalRGBColor> + Clone>(frame: &PlanarFrame, planes: &[Vec<u8>], init: T, upsampling: ChromaUpsampling) -> Vec<T> {
+        let slices: Vec<&[u8]> = planes.iter().map(|p| p.as_slice()).collect();
+        let mut output = vec![init; frame.width * frame.height];
+        frame.convert_to_rgb(&slices, &mut output, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::FixedPoint, upsampling).unwrap();
+        output
+    }
+
+    #[test]
+    fn plane_sizes() {
+        assert_eq!([5, 3, 3], PlanarFrame::new(PlanarFormat::I420, 5, 3).strides);
+        assert_eq!([15, 6, 6], PlanarFrame::new(PlanarFormat::YV12, 5, 3).plane_sizes());
+        assert_eq!([15, 12, 0], PlanarFrame::new(PlanarFormat::NV12, 5, 3).plane_sizes());
+        assert_eq!([36, 0, 0], PlanarFrame::new(PlanarFormat::UYVY, 5, 3).plane_sizes());
+
+        let padded = PlanarFrame{ strides: [8, 4, 4], ..PlanarFrame::new(PlanarFormat::I420, 5, 3) };
+        assert_eq!([24, 8, 8], padded.plane_sizes());
+    }
+
+    #[test]
+    fn uniform_round_trip() {
+        let color = DigitalRGBColor{ r: 200, g: 100, b: 50 };
+        let ycbcr = DigitalYCbCrColor::from_rgb_fixed(color, YCbCrMatrix::BT601, YCbCrRange::Full);
+        let expected = DigitalRGBColor::from_ycbcr_fixed(ycbcr, YCbCrMatrix::BT601, YCbCrRange::Full);
+
+        for format in FORMATS.iter() {
+            let frame = PlanarFrame::new(*format, 5, 3);
+            let mut planes = allocate(&frame);
+            write(&frame, &[color; 15], &mut planes, ChromaDownsampling::Average);
+
+            for upsampling in [ChromaUpsampling::Nearest, ChromaUpsampling::Bilinear] {
+                assert_eq!(vec![expected; 15], read(&frame, &planes, color, upsampling), "{:?}", format);
+            }
+
+            let rgba = read(&frame, &planes, DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 }, ChromaUpsampling::Nearest);
+            assert!(rgba.iter().all(|c| *c == DigitalRGBAColor::from(expected)), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn plane_layout() {
+        let colors = [
+            DigitalRGBColor{ r: 255, g: 0, b: 0 },
+            DigitalRGBColor{ r: 0, g: 255, b: 0 },
+            DigitalRGBColor{ r: 0, g: 0, b: 255 },
+            DigitalRGBColor{ r: 255, g: 255, b: 255 }
+        ];
+        let ycbcr: Vec<DigitalYCbCrColor> = colors.iter().map(|c| DigitalYCbCrColor::from_rgb_fixed(*c, YCbCrMatrix::BT601, YCbCrRange::Full)).collect();
+        let (y, cb, cr) = (ycbcr.iter().map(|c| c.y).collect::<Vec<u8>>(), ycbcr[0].cb, ycbcr[0].cr);
+
+        let mut i420 = allocate(&PlanarFrame::new(PlanarFormat::I420, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::I420, 2, 2), &colors, &mut i420, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![y.clone(), vec![cb], vec![cr]], i420);
+
+        let mut yv12 = allocate(&PlanarFrame::new(PlanarFormat::YV12, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::YV12, 2, 2), &colors, &mut yv12, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![y.clone(), vec![cr], vec![cb]], yv12);
+
+        let mut nv12 = allocate(&PlanarFrame::new(PlanarFormat::NV12, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::NV12, 2, 2), &colors, &mut nv12, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![y.clone(), vec![cb, cr]], nv12);
+
+        let mut nv21 = allocate(&PlanarFrame::new(PlanarFormat::NV21, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::NV21, 2, 2), &colors, &mut nv21, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![y.clone(), vec![cr, cb]], nv21);
+
+        let mut yuyv = allocate(&PlanarFrame::new(PlanarFormat::YUYV, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::YUYV, 2, 2), &colors, &mut yuyv, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![vec![y[0], cb, y[1], cr, y[2], ycbcr[2].cb, y[3], ycbcr[2].cr]], yuyv);
+
+        let mut uyvy = allocate(&PlanarFrame::new(PlanarFormat::UYVY, 2, 2));
+        write(&PlanarFrame::new(PlanarFormat::UYVY, 2, 2), &colors, &mut uyvy, ChromaDownsampling::TopLeft);
+        assert_eq!(vec![vec![cb, y[0], cr, y[1], ycbcr[2].cb, y[2], ycbcr[2].cr, y[3]]], uyvy);
+    }
+
+    #[test]
+    fn downsampling_average() {
+        let colors = [
+            DigitalRGBColor{ r: 255, g: 0, b: 0 },
+            DigitalRGBColor{ r: 0, g: 255, b: 0 },
+            DigitalRGBColor{ r: 0, g: 0, b: 255 }
+        ];
+        let ycbcr: Vec<DigitalYCbCrColor> = colors.iter().map(|c| DigitalYCbCrColor::from_rgb_fixed(*c, YCbCrMatrix::BT601, YCbCrRange::Full)).collect();
+        // the odd width leaves a block of a single column
+        let frame = PlanarFrame::new(PlanarFormat::NV12, 3, 2);
+        let mut planes = allocate(&frame);
+        let colors = [colors[0], colors[1], colors[2], colors[1], colors[2], colors[0]];
+        write(&frame, &colors, &mut planes, ChromaDownsampling::Average);
+
+        let cb = [ycbcr[0].cb as u32, ycbcr[1].cb as u32, ycbcr[2].cb as u32];
+        let cr = [ycbcr[0].cr as u32, ycbcr[1].cr as u32, ycbcr[2].cr as u32];
+        assert_eq!(((cb[0] + 2 * cb[1] + cb[2] + 2) / 4) as u8, planes[1][0]);
+        assert_eq!(((cr[0] + 2 * cr[1] + cr[2] + 2) / 4) as u8, planes[1][1]);
+        assert_eq!((cb[2] + cb[0]).div_ceil(2) as u8, planes[1][2]);
+        assert_eq!((cr[2] + cr[0]).div_ceil(2) as u8, planes[1][3]);
+    }
+
+    #[test]
+    fn upsampling_bilinear() {
+        let frame = PlanarFrame::new(PlanarFormat::YUYV, 4, 1);
+        let planes: [&[u8]; 1] = [&[0, 0, 0, 100, 0, 200, 0, 20]];
+        let cb: Vec<u8> = (0..4).map(|x| frame.chroma(&planes, x, 0, ChromaUpsampling::Bilinear)[0]).collect();
+        assert_eq!(vec![0, 50, 150, 200], cb);
+        let cr: Vec<u8> = (0..4).map(|x| frame.chroma(&planes, x, 0, ChromaUpsampling::Bilinear)[1]).collect();
+        assert_eq!(vec![100, 80, 40, 20], cr);
+        let cb: Vec<u8> = (0..4).map(|x| frame.chroma(&planes, x, 0, ChromaUpsampling::Nearest)[0]).collect();
+        assert_eq!(vec![0, 0, 200, 200], cb);
+
+        let frame = PlanarFrame::new(PlanarFormat::I420, 4, 4);
+        let planes: [&[u8]; 3] = [&[0; 16], &[0, 64, 128, 255], &[0; 4]];
+        let chroma = |x, y| frame.chroma(&planes, x, y, ChromaUpsampling::Bilinear)[0];
+        assert_eq!(0, chroma(0, 0));
+        assert_eq!(52, chroma(1, 1));
+        assert_eq!(92, chroma(2, 1));
+        assert_eq!(255, chroma(3, 3));
+    }
+
+    #[test]
+    fn strides() {
+        let colors: Vec<DigitalRGBColor> = (0..15).map(|i| DigitalRGBColor{ r: i * 17, g: 255 - i * 13, b: i * 5 }).collect();
+        let tight = PlanarFrame::new(PlanarFormat::I420, 5, 3);
+        let padded = PlanarFrame{ strides: [8, 4, 4], ..tight };
+
+        let mut tight_planes = allocate(&tight);
+        write(&tight, &colors, &mut tight_planes, ChromaDownsampling::Average);
+        let mut padded_planes = allocate(&padded);
+        write(&padded, &colors, &mut padded_planes, ChromaDownsampling::Average);
+
+        for (row, y) in padded_planes[0].chunks(8).enumerate() {
+            assert_eq!(&tight_planes[0][row * 5..row * 5 + 5], &y[..5]);
+            assert_eq!(&[0xaa; 3], &y[5..]);
+        }
+        assert_eq!(&[0xaa], &padded_planes[1][3..4]);
+
+        for upsampling in [ChromaUpsampling::Nearest, ChromaUpsampling::Bilinear] {
+            let init = DigitalRGBColor{ r: 0, g: 0, b: 0 };
+            assert_eq!(read(&tight, &tight_planes, init, upsampling), read(&padded, &padded_planes, init, upsampling));
+        }
+
+        // the last row doesn't need padding
+        let slices: Vec<&[u8]> = vec![&padded_planes[0][..21], &padded_planes[1][..7], &padded_planes[2][..7]];
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; 15];
+        assert_eq!(Ok(()), padded.convert_to_rgb(&slices, &mut output, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::FixedPoint, ChromaUpsampling::Nearest));
+    }
+
+    #[test]
+    fn invalid_planes() {
+        let frame = PlanarFrame::new(PlanarFormat::NV12, 4, 2);
+        let y = [0u8; 8];
+        let uv = [0u8; 4];
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; 8];
+        let convert = |frame: &PlanarFrame, planes: &[&[u8]], output: &mut [DigitalRGBColor]| {
+            frame.convert_to_rgb(planes, output, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::Float, ChromaUpsampling::Nearest)
+        };
+
+        assert_eq!(Ok(()), convert(&frame, &[&y, &uv], &mut output));
+        assert_eq!(Err(ColorConversionError::InvalidPlanes), convert(&frame, &[&y, &uv, &uv], &mut output));
+        assert_eq!(Err(ColorConversionError::InvalidPlanes), convert(&PlanarFrame{ strides: [3, 4, 0], ..frame }, &[&y, &uv], &mut output));
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), convert(&frame, &[&y[..7], &uv], &mut output));
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), convert(&frame, &[&y, &uv], &mut output[..7]));
+
+        let mut planes: [&mut [u8]; 1] = [&mut [0u8; 16]];
+        assert_eq!(
+            Err(ColorConversionError::InvalidPlanes),
+            frame.convert_from_rgb(&output, &mut planes, YCbCrMatrix::BT601, YCbCrRange::Full, YCbCrArithmetic::Float, ChromaDownsampling::Average)
+        );
+    }
+}
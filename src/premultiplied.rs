@@ -0,0 +1,244 @@
+use crate::*;
+
+/// Multiply two `u8` values with correct rounding, interpreting 255 as 1.0
+#[inline]
+pub(crate) fn mul_u8(c: u8, a: u8) -> u8 {
+    let t = c as u32 * a as u32 + 128;
+    ((t + (t >> 8)) >> 8) as u8
+}
+
+//
+// Native types to premultiplied RGBA
+//
+
+impl From<[u8; 4]> for DigitalPremultipliedRGBAColor {
+    fn from(octets: [u8; 4]) -> Self {
+        DigitalPremultipliedRGBAColor{
+            r: octets[0],
+            g: octets[1],
+            b: octets[2],
+            a: octets[3]
+        }
+    }
+}
+
+impl From<&[u8; 4]> for DigitalPremultipliedRGBAColor {
+    fn from(octets: &[u8; 4]) -> Self {
+        DigitalPremultipliedRGBAColor{
+            r: octets[0],
+            g: octets[1],
+            b: octets[2],
+            a: octets[3]
+        }
+    }
+}
+
+impl From<DigitalPremultipliedRGBAColor> for [u8; 4] {
+    fn from(f: DigitalPremultipliedRGBAColor) -> Self {
+        [f.r, f.g, f.b, f.a]
+    }
+}
+
+//
+// Premultiplied RGBA to premultiplied RGBA
+//
+
+impl From<DigitalPremultipliedRGBAColor> for NormalizedPremultipliedRGBAColor {
+    fn from(f: DigitalPremultipliedRGBAColor) -> Self {
+        NormalizedPremultipliedRGBAColor{
+            r: f.r as f32 / 255.0,
+            g: f.g as f32 / 255.0,
+            b: f.b as f32 / 255.0,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedPremultipliedRGBAColor> for DigitalPremultipliedRGBAColor {
+    fn from(f: NormalizedPremultipliedRGBAColor) -> Self {
+        let a = (f.a * 255.0).round().clamp(0.0, 255.0);
+
+        DigitalPremultipliedRGBAColor{
+            r: (f.r * 255.0).round().clamp(0.0, a) as u8,
+            g: (f.g * 255.0).round().clamp(0.0, a) as u8,
+            b: (f.b * 255.0).round().clamp(0.0, a) as u8,
+            a: a as u8
+        }
+    }
+}
+
+//
+// RGBA to premultiplied RGBA
+//
+
+impl From<DigitalRGBAColor> for DigitalPremultipliedRGBAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalPremultipliedRGBAColor{
+            r: mul_u8(f.r, f.a),
+            g: mul_u8(f.g, f.a),
+            b: mul_u8(f.b, f.a),
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedRGBAColor> for NormalizedPremultipliedRGBAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        NormalizedPremultipliedRGBAColor{
+            r: f.r * f.a,
+            g: f.g * f.a,
+            b: f.b * f.a,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalRGBAColor> for NormalizedPremultipliedRGBAColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        NormalizedPremultipliedRGBAColor::from(NormalizedRGBAColor::from(f))
+    }
+}
+
+impl From<NormalizedRGBAColor> for DigitalPremultipliedRGBAColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalPremultipliedRGBAColor::from(NormalizedPremultipliedRGBAColor::from(f))
+    }
+}
+
+//
+// RGB to premultiplied RGBA
+//
+
+impl From<DigitalRGBColor> for DigitalPremultipliedRGBAColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        DigitalPremultipliedRGBAColor{
+            r: f.r,
+            g: f.g,
+            b: f.b,
+            a: 255
+        }
+    }
+}
+
+impl From<NormalizedRGBColor> for NormalizedPremultipliedRGBAColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        NormalizedPremultipliedRGBAColor{
+            r: f.r,
+            g: f.g,
+            b: f.b,
+            a: 1.0
+        }
+    }
+}
+
+//
+// Flatten premultiplied RGBA onto a background
+//
+
+impl Flatten for DigitalPremultipliedRGBAColor {
+    type Output = DigitalRGBColor;
+
+    fn flatten_rgb(self, background: DigitalRGBColor) -> DigitalRGBColor {
+        let t = 255 - self.a;
+
+        DigitalRGBColor{
+            r: self.r.saturating_add(mul_u8(background.r, t)),
+            g: self.g.saturating_add(mul_u8(background.g, t)),
+            b: self.b.saturating_add(mul_u8(background.b, t))
+        }
+    }
+}
+
+impl Flatten for NormalizedPremultipliedRGBAColor {
+    type Output = NormalizedRGBColor;
+
+    fn flatten_rgb(self, background: DigitalRGBColor) -> NormalizedRGBColor {
+        let bg = NormalizedRGBColor::from(background);
+
+        NormalizedRGBColor{
+            r: self.r + bg.r * (1.0 - self.a),
+            g: self.g + bg.g * (1.0 - self.a),
+            b: self.b + bg.b * (1.0 - self.a)
+        }
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl PremultipliedRGBAConvertible for NormalizedPremultipliedRGBAColor {
+    fn convert_vec_premultiplied(items: Vec<Self>) -> Vec<DigitalPremultipliedRGBAColor> {
+        items.into_iter().map(DigitalPremultipliedRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for DigitalPremultipliedRGBAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedPremultipliedRGBAColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn premultiply_d() {
+        let c = DigitalRGBAColor{ r: 255, g: 128, b: 0, a: 128 };
+        let p = DigitalPremultipliedRGBAColor::from(c);
+        assert_eq!(DigitalPremultipliedRGBAColor{ r: 128, g: 64, b: 0, a: 128 }, p);
+    }
+
+    #[test]
+    fn premultiply_n() {
+        let c = NormalizedRGBAColor{ r: 1.0, g: 0.5, b: 0.0, a: 0.5 };
+        let p = NormalizedPremultipliedRGBAColor::from(c);
+        assert_eq!(NormalizedPremultipliedRGBAColor{ r: 0.5, g: 0.25, b: 0.0, a: 0.5 }, p);
+    }
+
+    #[test]
+    fn premultiply_matches_float() {
+        for c in 0..=255u8 {
+            for a in 0..=255u8 {
+                let expected = (c as f32 * a as f32 / 255.0).round() as u8;
+                assert_eq!(expected, super::mul_u8(c, a), "{} * {}", c, a);
+            }
+        }
+    }
+
+    #[test]
+    fn transparent_is_black() {
+        let p = DigitalPremultipliedRGBAColor::from(DigitalRGBAColor{ r: 10, g: 20, b: 30, a: 0 });
+        assert_eq!(DigitalPremultipliedRGBAColor{ r: 0, g: 0, b: 0, a: 0 }, p);
+        assert_eq!(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 }, DigitalRGBAColor::from(p));
+
+        let p = NormalizedPremultipliedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
+        assert_eq!(NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 }, NormalizedRGBAColor::from(p));
+    }
+
+    #[test]
+    fn unpremultiply_roundtrip() {
+        for c in 0..=255u8 {
+            for a in 1..=255u8 {
+                let rgba = DigitalRGBAColor{ r: c, g: c, b: c, a };
+                let back = DigitalRGBAColor::from(DigitalPremultipliedRGBAColor::from(rgba));
+                let tolerance = (255.0 / a as f32 / 2.0).ceil() as i16;
+                assert!((back.r as i16 - c as i16).abs() <= tolerance, "{:?} != {:?}", rgba, back);
+                assert_eq!(a, back.a);
+            }
+        }
+        let opaque = DigitalRGBAColor{ r: 1, g: 2, b: 3, a: 255 };
+        assert_eq!(opaque, DigitalRGBAColor::from(DigitalPremultipliedRGBAColor::from(opaque)));
+    }
+}
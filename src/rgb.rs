@@ -1,5 +1,8 @@
 use std::convert::TryFrom;
 use crate::*;
+use crate::bit16::{u16_to_f32, u16_to_u8};
+use crate::fixed::{self, FixedYCbCr};
+use crate::packed::{field, replicate};
 
 //
 // Native types to RGB
@@ -91,41 +94,41 @@ impl From<u32> for NormalizedRGBColor {
     }
 }
 
-impl Into<[u8; 3]> for DigitalRGBColor {
-    fn into(self) -> [u8; 3] {
-        [self.r, self.g, self.b]
+impl From<DigitalRGBColor> for [u8; 3] {
+    fn from(f: DigitalRGBColor) -> Self {
+        [f.r, f.g, f.b]
     }
 }
 
-impl Into<[u8; 4]> for DigitalRGBColor {
-    fn into(self) -> [u8; 4] {
-        [self.r, self.g, self.b, 255]
+impl From<DigitalRGBColor> for [u8; 4] {
+    fn from(f: DigitalRGBColor) -> Self {
+        [f.r, f.g, f.b, 255]
     }
 }
 
-impl Into<u32> for DigitalRGBColor {
-    fn into(self) -> u32 {
-        (self.r as u32) << 24 +
-        (self.g as u32) << 16 +
-        (self.b as u32) << 8
+impl From<DigitalRGBColor> for u32 {
+    fn from(f: DigitalRGBColor) -> Self {
+        (f.r as u32) << 24 |
+        (f.g as u32) << 16 |
+        (f.b as u32) << 8
     }
 }
 
-impl Into<[u8; 3]> for NormalizedRGBColor {
-    fn into(self) -> [u8; 3] {
-        DigitalRGBColor::from(self).into()
+impl From<NormalizedRGBColor> for [u8; 3] {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalRGBColor::from(f).into()
     }
 }
 
-impl Into<[u8; 4]> for NormalizedRGBColor {
-    fn into(self) -> [u8; 4] {
-        DigitalRGBColor::from(self).into()
+impl From<NormalizedRGBColor> for [u8; 4] {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalRGBColor::from(f).into()
     }
 }
 
-impl Into<u32> for NormalizedRGBColor {
-    fn into(self) -> u32 {
-        DigitalRGBColor::from(self).into()
+impl From<NormalizedRGBColor> for u32 {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalRGBColor::from(f).into()
     }
 }
 
@@ -233,6 +236,76 @@ impl From<DigitalYCbCrColor> for DigitalRGBColor {
     }
 }
 
+impl NormalizedRGBColor {
+    /// Convert a YCbCr color to RGB using the coefficients of `matrix`
+    pub fn from_ycbcr(f: NormalizedYCbCrColor, matrix: YCbCrMatrix) -> Self {
+        let kr = matrix.kr();
+        let kb = matrix.kb();
+        let r = f.y + 2.0 * (1.0 - kr) * f.cr;
+        let b = f.y + 2.0 * (1.0 - kb) * f.cb;
+
+        NormalizedRGBColor{
+            r,
+            g: (f.y - kr * r - kb * b) / matrix.kg(),
+            b
+        }
+    }
+}
+
+impl DigitalRGBColor {
+    /// Convert a YCbCr color that has been quantized to `range` to RGB using the coefficients of `matrix`
+    pub fn from_ycbcr(f: DigitalYCbCrColor, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        let c = NormalizedRGBColor::from_ycbcr(NormalizedYCbCrColor::from_digital(f, range), matrix);
+
+        DigitalRGBColor{
+            r: (c.r * 255.0).round().clamp(0.0, 255.0) as u8,
+            g: (c.g * 255.0).round().clamp(0.0, 255.0) as u8,
+            b: (c.b * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+
+    /// Convert a YCbCr color that has been quantized to `range` to RGB using 16 bit fixed-point
+    /// coefficients of `matrix`
+    ///
+    /// The result is bit-exact with libjpeg for `BT601` in full range and may differ by one from `from_ycbcr`.
+    pub fn from_ycbcr_fixed(f: DigitalYCbCrColor, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        FixedYCbCr::new(matrix, range).decode(f)
+    }
+
+    /// Convert a vector of RGB colors into a vector of `DigitalYCbCrColor` values quantized to `range`
+    /// using the coefficients of `matrix` and `arithmetic`
+    pub fn convert_vec_ycbcr_with(items: Vec<Self>, matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Vec<DigitalYCbCrColor> {
+        let mut output = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; items.len()];
+        fixed::rgb_to_ycbcr(&items, &mut output, matrix, range, arithmetic);
+        output
+    }
+
+    /// Convert a slice of RGB colors into a buffer of `DigitalYCbCrColor` values quantized to `range`
+    /// using the coefficients of `matrix` and `arithmetic`
+    pub fn convert_slice_ycbcr_with(items: &[Self], output: &mut [DigitalYCbCrColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        fixed::rgb_to_ycbcr(items, output, matrix, range, arithmetic);
+        Ok(())
+    }
+
+    /// Convert a vector of RGB colors into a vector of `DigitalYCbCrColor` values quantized to `range`
+    /// using the coefficients of `matrix` and `arithmetic` on multiple threads
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_vec_ycbcr_with(items: Vec<Self>, matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Vec<DigitalYCbCrColor> {
+        parallel::convert_vec(items, |i, o| Self::convert_slice_ycbcr_with(i, o, matrix, range, arithmetic))
+    }
+
+    /// Convert a slice of RGB colors into a buffer of `DigitalYCbCrColor` values quantized to `range`
+    /// using the coefficients of `matrix` and `arithmetic` on multiple threads
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_slice_ycbcr_with(items: &[Self], output: &mut [DigitalYCbCrColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Result<(), ColorConversionError> {
+        parallel::convert_slice(items, output, |i, o| Self::convert_slice_ycbcr_with(i, o, matrix, range, arithmetic))
+    }
+}
+
 //
 // RGBA to RGB
 //
@@ -278,6 +351,153 @@ impl From<DigitalRGBAColor> for NormalizedRGBColor {
     }
 }
 
+//
+// HSL to RGB
+//
+
+impl From<NormalizedHSLColor> for NormalizedRGBColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        let c = (1.0 - (2.0 * f.l - 1.0).abs()) * f.s;
+        hue_chroma_to_rgb(f.h, c, f.l - c / 2.0)
+    }
+}
+
+impl From<DigitalHSLColor> for NormalizedRGBColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedRGBColor::from(NormalizedHSLColor::from(f))
+    }
+}
+
+impl From<NormalizedHSLColor> for DigitalRGBColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalRGBColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        DigitalRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// HSV to RGB
+//
+
+impl From<NormalizedHSVColor> for NormalizedRGBColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        let c = f.v * f.s;
+        hue_chroma_to_rgb(f.h, c, f.v - c)
+    }
+}
+
+impl From<DigitalHSVColor> for NormalizedRGBColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedRGBColor::from(NormalizedHSVColor::from(f))
+    }
+}
+
+impl From<NormalizedHSVColor> for DigitalRGBColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalRGBColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        DigitalRGBColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// Build a RGB color from a hue (fraction of a full circle), the chroma `c` and
+/// the offset `m` that is added to all components to match lightness or value.
+#[inline]
+fn hue_chroma_to_rgb(h: f32, c: f32, m: f32) -> NormalizedRGBColor {
+    let h = h.rem_euclid(1.0) * 6.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x)
+    };
+
+    NormalizedRGBColor{
+        r: r + m,
+        g: g + m,
+        b: b + m
+    }
+}
+
+//
+// YCgCo to RGB
+//
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedYCgCoColor> for NormalizedRGBColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        let t = f.y - f.cg;
+
+        NormalizedRGBColor{
+            r: t + f.co,
+            g: f.y + f.cg,
+            b: t - f.co
+        }
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalYCgCoColor> for NormalizedRGBColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        NormalizedRGBColor::from(NormalizedYCgCoColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedYCgCoColor> for DigitalRGBColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        let c = NormalizedRGBColor::from(f);
+
+        DigitalRGBColor{
+            r: (c.r * 255.0).round().clamp(0.0, 255.0) as u8,
+            g: (c.g * 255.0).round().clamp(0.0, 255.0) as u8,
+            b: (c.b * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalYCgCoColor> for DigitalRGBColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        DigitalRGBColor::from(NormalizedYCgCoColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalYCoCgRColor> for DigitalRGBColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        let t = f.y - (f.cg >> 1);
+        let g = f.cg + t;
+        let b = t - (f.co >> 1);
+        let r = b + f.co;
+
+        DigitalRGBColor{
+            r: r.clamp(0, 255) as u8,
+            g: g.clamp(0, 255) as u8,
+            b: b.clamp(0, 255) as u8
+        }
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalYCoCgRColor> for NormalizedRGBColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        NormalizedRGBColor::from(DigitalRGBColor::from(f))
+    }
+}
+
 //
 // CIE XYZ to RGB
 //
@@ -294,14 +514,30 @@ impl From<CIEXYZColor> for NormalizedRGBColor {
 /// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
 impl From<CIEXYZColor> for DigitalRGBColor {
     fn from(f: CIEXYZColor) -> Self {
-        let r = f.x * 3.2404541621141054 - f.y * 1.5371385127977166 - f.z * 0.4985314095560162;
-        let g = f.x * -0.9692660305051868 + f.y * 1.8760108454466942 + f.z * 0.04155601753034984;
-        let b = f.x * 0.05564343095911469 - f.y * 0.20402591351675387 + f.z * 1.0572251882231791;
+        DigitalRGBColor::from(LinearRGBColor::from(f))
+    }
+}
 
+//
+// Linear RGB to RGB
+//
+
+impl From<LinearRGBColor> for NormalizedRGBColor {
+    fn from(f: LinearRGBColor) -> Self {
+        NormalizedRGBColor{
+            r: linear_to_srgb(f.r),
+            g: linear_to_srgb(f.g),
+            b: linear_to_srgb(f.b)
+        }
+    }
+}
+
+impl From<LinearRGBColor> for DigitalRGBColor {
+    fn from(f: LinearRGBColor) -> Self {
         DigitalRGBColor{
-            r: xyz_to_rgb_map(r),
-            g: xyz_to_rgb_map(g),
-            b: xyz_to_rgb_map(b)
+            r: linear_to_rgb_map(f.r),
+            g: linear_to_rgb_map(f.g),
+            b: linear_to_rgb_map(f.b)
         }
     }
 }
@@ -325,77 +561,282 @@ impl From<CIELabColor> for DigitalRGBColor {
 }
 
 #[inline]
-fn xyz_to_rgb_map(c: f32) -> u8 {
-    ((if c > 0.0031308 {
-        1.055 * c.powf(1.0 / 2.4) - 0.055
-    } else {
-        12.92 * c
-    }) * 255.0)
+fn linear_to_rgb_map(c: f32) -> u8 {
+    (linear_to_srgb(c) * 255.0)
         .round()
-        .min(255.0)
-        .max(0.0) as u8
+        .clamp(0.0, 255.0) as u8
+}
+
+
+//
+// RGB16 to RGB
+//
+
+impl From<DigitalRGB16Color> for NormalizedRGBColor {
+    fn from(f: DigitalRGB16Color) -> Self {
+        NormalizedRGBColor{
+            r: u16_to_f32(f.r),
+            g: u16_to_f32(f.g),
+            b: u16_to_f32(f.b)
+        }
+    }
+}
+
+impl From<DigitalRGB16Color> for DigitalRGBColor {
+    fn from(f: DigitalRGB16Color) -> Self {
+        DigitalRGBColor{
+            r: u16_to_u8(f.r),
+            g: u16_to_u8(f.g),
+            b: u16_to_u8(f.b)
+        }
+    }
+}
+
+//
+// Packed RGB to RGB
+//
+
+impl From<PackedRGB565Color> for DigitalRGBColor {
+    fn from(f: PackedRGB565Color) -> Self {
+        let v = f.0 as u32;
+
+        DigitalRGBColor{
+            r: replicate(field(v, 11, 5), 5, 8) as u8,
+            g: replicate(field(v, 5, 6), 6, 8) as u8,
+            b: replicate(field(v, 0, 5), 5, 8) as u8
+        }
+    }
 }
 
+impl From<PackedRGB555Color> for DigitalRGBColor {
+    fn from(f: PackedRGB555Color) -> Self {
+        let v = f.0 as u32;
+
+        DigitalRGBColor{
+            r: replicate(field(v, 10, 5), 5, 8) as u8,
+            g: replicate(field(v, 5, 5), 5, 8) as u8,
+            b: replicate(field(v, 0, 5), 5, 8) as u8
+        }
+    }
+}
+
+impl From<PackedRGBA4444Color> for DigitalRGBColor {
+    fn from(f: PackedRGBA4444Color) -> Self {
+        DigitalRGBColor::from(DigitalRGBAColor::from(f))
+    }
+}
+
+impl From<PackedRGBA5551Color> for DigitalRGBColor {
+    fn from(f: PackedRGBA5551Color) -> Self {
+        DigitalRGBColor::from(DigitalRGBAColor::from(f))
+    }
+}
+
+impl From<PackedRGB10A2Color> for DigitalRGBColor {
+    fn from(f: PackedRGB10A2Color) -> Self {
+        DigitalRGBColor::from(DigitalRGBAColor::from(f))
+    }
+}
 
 // 
 // Color conversion traits
 //
 
+impl GrayscaleConvertible for DigitalRGBColor {
+    fn convert_vec_grayscale(items: Vec<Self>) -> Vec<DigitalGrayscaleColor> {
+        let mut output = vec![DigitalGrayscaleColor{ v: 0 }; items.len()];
+        simd::rgb_to_grayscale(&items, &mut output);
+        output
+    }
+
+    fn convert_iter_grayscale(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalGrayscaleColor>> {
+        Box::new(items.map(DigitalGrayscaleColor::from))
+    }
+
+    fn convert_slice_grayscale(items: &[Self], output: &mut [DigitalGrayscaleColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::rgb_to_grayscale(items, output);
+        Ok(())
+    }
+}
+
 impl YCbCrConvertible for DigitalRGBColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        let mut output = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; items.len()];
+        simd::rgb_to_ycbcr(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_ycbcr(items: &[Self], output: &mut [DigitalYCbCrColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::rgb_to_ycbcr(items, output);
+        Ok(())
     }
 }
 
 impl YCbCrConvertible for NormalizedRGBColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl RGBConvertible for NormalizedRGBColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBColor::from).collect()
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl HSLConvertible for DigitalRGBColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for DigitalRGBColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for NormalizedRGBColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for NormalizedRGBColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl YCgCoConvertible for DigitalRGBColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
+    }
+}
+
+impl YCgCoConvertible for NormalizedRGBColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
     }
 }
 
 impl RGBAConvertible for DigitalRGBColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl RGBAConvertible for NormalizedRGBColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl CIELabConvertible for DigitalRGBColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        let mut output = vec![CIELabColor{ l: 0.0, a: 0.0, b: 0.0 }; items.len()];
+        simd::rgb_to_lab(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_lab(items: &[Self], output: &mut [CIELabColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::rgb_to_lab(items, output);
+        Ok(())
     }
 }
 
 impl CIELabConvertible for NormalizedRGBColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        let mut output = vec![CIELabColor{ l: 0.0, a: 0.0, b: 0.0 }; items.len()];
+        simd::normalized_rgb_to_lab(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_lab(items: &[Self], output: &mut [CIELabColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::normalized_rgb_to_lab(items, output);
+        Ok(())
     }
 }
 
 impl CIEXYZConvertible for DigitalRGBColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        let mut output = vec![CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 }; items.len()];
+        simd::rgb_to_xyz(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_xyz(items: &[Self], output: &mut [CIEXYZColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::rgb_to_xyz(items, output);
+        Ok(())
     }
 }
 
 impl CIEXYZConvertible for NormalizedRGBColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        let mut output = vec![CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 }; items.len()];
+        simd::normalized_rgb_to_xyz(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_xyz(items: &[Self], output: &mut [CIEXYZColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::normalized_rgb_to_xyz(items, output);
+        Ok(())
+    }
+}
+
+impl RGB16Convertible for DigitalRGBColor {
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color> {
+        items.into_iter().map(DigitalRGB16Color::from).collect()
+    }
+}
+
+impl RGB16Convertible for NormalizedRGBColor {
+    fn convert_vec_rgb16(items: Vec<Self>) -> Vec<DigitalRGB16Color> {
+        items.into_iter().map(DigitalRGB16Color::from).collect()
     }
 }
 
@@ -504,5 +945,10 @@ mod tests {
             assert_eq!(test.0, DigitalRGBColor::from(test.1))
         }
     }
-}
 
+    #[test]
+    fn rgb_d_2_u32() {
+        assert_eq!(0x11223300u32, u32::from(DigitalRGBColor{ r: 0x11, g: 0x22, b: 0x33 }));
+        assert_eq!(0xff000000u32, u32::from(DigitalRGBColor{ r: 255, g: 0, b: 0 }));
+    }
+}
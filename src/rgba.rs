@@ -1,5 +1,7 @@
 use std::convert::TryFrom;
 use crate::*;
+use crate::bit16::{u16_to_f32, u16_to_u8};
+use crate::packed::{field, reduce, replicate};
 
 //
 // Native types to RGBA
@@ -108,42 +110,42 @@ impl From<u32> for NormalizedRGBAColor {
     }
 }
 
-impl Into<[u8; 3]> for DigitalRGBAColor {
-    fn into(self) -> [u8; 3] {
-        [self.r, self.g, self.b]
+impl From<DigitalRGBAColor> for [u8; 3] {
+    fn from(f: DigitalRGBAColor) -> Self {
+        [f.r, f.g, f.b]
     }
 }
 
-impl Into<[u8; 4]> for DigitalRGBAColor {
-    fn into(self) -> [u8; 4] {
-        [self.r, self.g, self.b, self.a]
+impl From<DigitalRGBAColor> for [u8; 4] {
+    fn from(f: DigitalRGBAColor) -> Self {
+        [f.r, f.g, f.b, f.a]
     }
 }
 
-impl Into<u32> for DigitalRGBAColor {
-    fn into(self) -> u32 {
-        (self.r as u32) << 24 +
-        (self.g as u32) << 16 +
-        (self.b as u32) << 8 +
-        (self.a as u32)
+impl From<DigitalRGBAColor> for u32 {
+    fn from(f: DigitalRGBAColor) -> Self {
+        (f.r as u32) << 24 |
+        (f.g as u32) << 16 |
+        (f.b as u32) << 8 |
+        (f.a as u32)
     }
 }
 
-impl Into<[u8; 3]> for NormalizedRGBAColor {
-    fn into(self) -> [u8; 3] {
-        DigitalRGBAColor::from(self).into()
+impl From<NormalizedRGBAColor> for [u8; 3] {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalRGBAColor::from(f).into()
     }
 }
 
-impl Into<[u8; 4]> for NormalizedRGBAColor {
-    fn into(self) -> [u8; 4] {
-        DigitalRGBAColor::from(self).into()
+impl From<NormalizedRGBAColor> for [u8; 4] {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalRGBAColor::from(f).into()
     }
 }
 
-impl Into<u32> for NormalizedRGBAColor {
-    fn into(self) -> u32 {
-        DigitalRGBAColor::from(self).into()
+impl From<NormalizedRGBAColor> for u32 {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalRGBAColor::from(f).into()
     }
 }
 
@@ -272,6 +274,54 @@ impl From<NormalizedRGBColor> for NormalizedRGBAColor {
 }
 
 
+//
+// Premultiplied RGBA to RGBA
+//
+
+impl From<DigitalPremultipliedRGBAColor> for DigitalRGBAColor {
+    fn from(f: DigitalPremultipliedRGBAColor) -> Self {
+        if f.a == 0 {
+            return DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 };
+        }
+        let unpremultiply = |c: u8| ((c as u32 * 255 + f.a as u32 / 2) / f.a as u32).min(255) as u8;
+
+        DigitalRGBAColor{
+            r: unpremultiply(f.r),
+            g: unpremultiply(f.g),
+            b: unpremultiply(f.b),
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedPremultipliedRGBAColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedPremultipliedRGBAColor) -> Self {
+        if f.a == 0.0 {
+            return NormalizedRGBAColor{ r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
+        }
+
+        NormalizedRGBAColor{
+            r: f.r / f.a,
+            g: f.g / f.a,
+            b: f.b / f.a,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalPremultipliedRGBAColor> for NormalizedRGBAColor {
+    fn from(f: DigitalPremultipliedRGBAColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedPremultipliedRGBAColor::from(f))
+    }
+}
+
+impl From<NormalizedPremultipliedRGBAColor> for DigitalRGBAColor {
+    fn from(f: NormalizedPremultipliedRGBAColor) -> Self {
+        DigitalRGBAColor::from(NormalizedRGBAColor::from(f))
+    }
+}
+
+
 //
 // CIE to RGBA
 //
@@ -337,48 +387,690 @@ impl From<DigitalYCbCrColor> for NormalizedRGBAColor {
     }
 }
 
+//
+// HSL to RGBA
+//
+
+impl From<NormalizedHSLColor> for DigitalRGBAColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSLColor> for DigitalRGBAColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedHSLColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSLColor> for NormalizedRGBAColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// HSV to RGBA
+//
+
+impl From<NormalizedHSVColor> for DigitalRGBAColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSVColor> for DigitalRGBAColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<NormalizedHSVColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+impl From<DigitalHSVColor> for NormalizedRGBAColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// YCgCo to RGBA
+//
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedYCgCoColor> for DigitalRGBAColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedYCgCoColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalYCgCoColor> for DigitalRGBAColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalYCgCoColor> for NormalizedRGBAColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalYCoCgRColor> for DigitalRGBAColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalYCoCgRColor> for NormalizedRGBAColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        NormalizedRGBAColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+//
+// Alpha colors to RGBA
+//
+
+impl From<DigitalGrayscaleAlphaColor> for DigitalRGBAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = DigitalRGBColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for DigitalRGBAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = DigitalRGBColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for DigitalRGBAColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = DigitalRGBColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for DigitalRGBAColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = DigitalRGBColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: (f.a * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIELabAColor> for DigitalRGBAColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = DigitalRGBColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for DigitalRGBAColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = DigitalRGBColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        DigitalRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: (f.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalGrayscaleAlphaColor> for NormalizedRGBAColor {
+    fn from(f: DigitalGrayscaleAlphaColor) -> Self {
+        let c = NormalizedRGBColor::from(DigitalGrayscaleColor{ v: f.v });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedGrayscaleAlphaColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedGrayscaleAlphaColor) -> Self {
+        let c = NormalizedRGBColor::from(NormalizedGrayscaleColor{ v: f.v });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a
+        }
+    }
+}
+
+impl From<DigitalYCbCrAColor> for NormalizedRGBAColor {
+    fn from(f: DigitalYCbCrAColor) -> Self {
+        let c = NormalizedRGBColor::from(DigitalYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a as f32 / 255.0
+        }
+    }
+}
+
+impl From<NormalizedYCbCrAColor> for NormalizedRGBAColor {
+    fn from(f: NormalizedYCbCrAColor) -> Self {
+        let c = NormalizedRGBColor::from(NormalizedYCbCrColor{ y: f.y, cb: f.cb, cr: f.cr });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.a
+        }
+    }
+}
+
+impl From<CIELabAColor> for NormalizedRGBAColor {
+    fn from(f: CIELabAColor) -> Self {
+        let c = NormalizedRGBColor::from(CIELabColor{ l: f.l, a: f.a, b: f.b });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.alpha
+        }
+    }
+}
+
+impl From<CIEXYZAColor> for NormalizedRGBAColor {
+    fn from(f: CIEXYZAColor) -> Self {
+        let c = NormalizedRGBColor::from(CIEXYZColor{ x: f.x, y: f.y, z: f.z });
+
+        NormalizedRGBAColor{
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: f.alpha
+        }
+    }
+}
+
+//
+// Flatten RGBA onto a background
+//
+
+impl Flatten for DigitalRGBAColor {
+    type Output = DigitalRGBColor;
+
+    fn flatten_rgb(self, background: DigitalRGBColor) -> DigitalRGBColor {
+        let a = self.a as u32;
+        let blend = |c: u8, bg: u8| {
+            let t = c as u32 * a + bg as u32 * (255 - a) + 128;
+            ((t + (t >> 8)) >> 8) as u8
+        };
+
+        DigitalRGBColor{
+            r: blend(self.r, background.r),
+            g: blend(self.g, background.g),
+            b: blend(self.b, background.b)
+        }
+    }
+}
+
+impl Flatten for NormalizedRGBAColor {
+    type Output = NormalizedRGBColor;
+
+    fn flatten_rgb(self, background: DigitalRGBColor) -> NormalizedRGBColor {
+        let bg = NormalizedRGBColor::from(background);
+
+        NormalizedRGBColor{
+            r: self.r * self.a + bg.r * (1.0 - self.a),
+            g: self.g * self.a + bg.g * (1.0 - self.a),
+            b: self.b * self.a + bg.b * (1.0 - self.a)
+        }
+    }
+}
+
+//
+// RGBA16 to RGBA
+//
+
+impl From<DigitalRGBA16Color> for NormalizedRGBAColor {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        NormalizedRGBAColor{
+            r: u16_to_f32(f.r),
+            g: u16_to_f32(f.g),
+            b: u16_to_f32(f.b),
+            a: u16_to_f32(f.a)
+        }
+    }
+}
+
+impl From<DigitalRGBA16Color> for DigitalRGBAColor {
+    fn from(f: DigitalRGBA16Color) -> Self {
+        DigitalRGBAColor{
+            r: u16_to_u8(f.r),
+            g: u16_to_u8(f.g),
+            b: u16_to_u8(f.b),
+            a: u16_to_u8(f.a)
+        }
+    }
+}
+
+//
+// Packed RGB to RGBA
+//
+
+impl From<PackedRGB565Color> for DigitalRGBAColor {
+    fn from(f: PackedRGB565Color) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<PackedRGB555Color> for DigitalRGBAColor {
+    fn from(f: PackedRGB555Color) -> Self {
+        DigitalRGBAColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<PackedRGBA4444Color> for DigitalRGBAColor {
+    fn from(f: PackedRGBA4444Color) -> Self {
+        let v = f.0 as u32;
+
+        DigitalRGBAColor{
+            r: replicate(field(v, 12, 4), 4, 8) as u8,
+            g: replicate(field(v, 8, 4), 4, 8) as u8,
+            b: replicate(field(v, 4, 4), 4, 8) as u8,
+            a: replicate(field(v, 0, 4), 4, 8) as u8
+        }
+    }
+}
+
+impl From<PackedRGBA5551Color> for DigitalRGBAColor {
+    fn from(f: PackedRGBA5551Color) -> Self {
+        let v = f.0 as u32;
+
+        DigitalRGBAColor{
+            r: replicate(field(v, 11, 5), 5, 8) as u8,
+            g: replicate(field(v, 6, 5), 5, 8) as u8,
+            b: replicate(field(v, 1, 5), 5, 8) as u8,
+            a: replicate(field(v, 0, 1), 1, 8) as u8
+        }
+    }
+}
+
+impl From<PackedRGB10A2Color> for DigitalRGBAColor {
+    fn from(f: PackedRGB10A2Color) -> Self {
+        DigitalRGBAColor{
+            r: reduce(field(f.0, 0, 10), 1023, 8) as u8,
+            g: reduce(field(f.0, 10, 10), 1023, 8) as u8,
+            b: reduce(field(f.0, 20, 10), 1023, 8) as u8,
+            a: replicate(field(f.0, 30, 2), 2, 8) as u8
+        }
+    }
+}
+
+impl From<PackedRGB10A2Color> for NormalizedRGBAColor {
+    fn from(f: PackedRGB10A2Color) -> Self {
+        NormalizedRGBAColor{
+            r: field(f.0, 0, 10) as f32 / 1023.0,
+            g: field(f.0, 10, 10) as f32 / 1023.0,
+            b: field(f.0, 20, 10) as f32 / 1023.0,
+            a: field(f.0, 30, 2) as f32 / 3.0
+        }
+    }
+}
+
 // 
 // Color conversion traits
 //
 
 impl YCbCrConvertible for DigitalRGBAColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl YCbCrConvertible for NormalizedRGBAColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
+    }
+}
+
+impl YCgCoConvertible for DigitalRGBAColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
+    }
+}
+
+impl YCgCoConvertible for NormalizedRGBAColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
+    }
+}
+
+impl GrayscaleConvertible for DigitalRGBAColor {
+    fn convert_vec_grayscale(items: Vec<Self>) -> Vec<DigitalGrayscaleColor> {
+        items.into_iter().map(DigitalGrayscaleColor::from).collect()
+    }
+
+    fn convert_iter_grayscale(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalGrayscaleColor>> {
+        Box::new(items.map(DigitalGrayscaleColor::from))
+    }
+}
+
+impl RGBConvertible for DigitalRGBAColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl GrayscaleConvertible for NormalizedRGBAColor {
+    fn convert_vec_grayscale(items: Vec<Self>) -> Vec<DigitalGrayscaleColor> {
+        items.into_iter().map(DigitalGrayscaleColor::from).collect()
+    }
+
+    fn convert_iter_grayscale(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalGrayscaleColor>> {
+        Box::new(items.map(DigitalGrayscaleColor::from))
+    }
+}
+
+impl RGBConvertible for NormalizedRGBAColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl GrayscaleAlphaConvertible for DigitalRGBAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for DigitalRGBAColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for DigitalRGBAColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for DigitalRGBAColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl GrayscaleAlphaConvertible for NormalizedRGBAColor {
+    fn convert_vec_grayscale_alpha(items: Vec<Self>) -> Vec<DigitalGrayscaleAlphaColor> {
+        items.into_iter().map(DigitalGrayscaleAlphaColor::from).collect()
+    }
+}
+
+impl YCbCrAConvertible for NormalizedRGBAColor {
+    fn convert_vec_ycbcra(items: Vec<Self>) -> Vec<DigitalYCbCrAColor> {
+        items.into_iter().map(DigitalYCbCrAColor::from).collect()
+    }
+}
+
+impl CIELabAConvertible for NormalizedRGBAColor {
+    fn convert_vec_laba(items: Vec<Self>) -> Vec<CIELabAColor> {
+        items.into_iter().map(CIELabAColor::from).collect()
+    }
+}
+
+impl CIEXYZAConvertible for NormalizedRGBAColor {
+    fn convert_vec_xyza(items: Vec<Self>) -> Vec<CIEXYZAColor> {
+        items.into_iter().map(CIEXYZAColor::from).collect()
+    }
+}
+
+impl PremultipliedRGBAConvertible for DigitalRGBAColor {
+    fn convert_vec_premultiplied(items: Vec<Self>) -> Vec<DigitalPremultipliedRGBAColor> {
+        let mut output = vec![DigitalPremultipliedRGBAColor{ r: 0, g: 0, b: 0, a: 0 }; items.len()];
+        simd::premultiply(&items, &mut output);
+        output
+    }
+
+    fn convert_slice_premultiplied(items: &[Self], output: &mut [DigitalPremultipliedRGBAColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::premultiply(items, output);
+        Ok(())
+    }
+}
+
+impl PremultipliedRGBAConvertible for NormalizedRGBAColor {
+    fn convert_vec_premultiplied(items: Vec<Self>) -> Vec<DigitalPremultipliedRGBAColor> {
+        items.into_iter().map(DigitalPremultipliedRGBAColor::from).collect()
     }
 }
 
 impl RGBAConvertible for NormalizedRGBAColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl CIELabConvertible for DigitalRGBAColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIELabConvertible for NormalizedRGBAColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for DigitalRGBAColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for NormalizedRGBAColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl HSLConvertible for DigitalRGBAColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for DigitalRGBAColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for NormalizedRGBAColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for NormalizedRGBAColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl RGBA16Convertible for DigitalRGBAColor {
+    fn convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color> {
+        items.into_iter().map(DigitalRGBA16Color::from).collect()
+    }
+}
+
+impl RGBA16Convertible for NormalizedRGBAColor {
+    fn convert_vec_rgba16(items: Vec<Self>) -> Vec<DigitalRGBA16Color> {
+        items.into_iter().map(DigitalRGBA16Color::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static WHITE: DigitalRGBColor = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+
+    #[test]
+    fn flatten_d() {
+        let c = DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 128 };
+        assert_eq!(DigitalRGBColor{ r: 255, g: 127, b: 127 }, c.flatten_rgb(WHITE));
+
+        let transparent = DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 0 };
+        assert_eq!(WHITE, transparent.flatten_rgb(WHITE));
+
+        let opaque = DigitalRGBAColor{ r: 12, g: 34, b: 56, a: 255 };
+        assert_eq!(DigitalRGBColor{ r: 12, g: 34, b: 56 }, opaque.flatten_rgb(WHITE));
+    }
+
+    #[test]
+    fn flatten_n() {
+        let c = NormalizedRGBAColor{ r: 1.0, g: 0.0, b: 0.0, a: 0.25 };
+        let bg = DigitalRGBColor{ r: 0, g: 0, b: 255 };
+        assert_eq!(NormalizedRGBColor{ r: 0.25, g: 0.0, b: 0.75 }, c.flatten_rgb(bg));
+    }
+
+    #[test]
+    fn flatten_premultiplied_matches_straight() {
+        let bg = DigitalRGBColor{ r: 10, g: 200, b: 30 };
+        for a in 0..=255 {
+            let c = DigitalRGBAColor{ r: 255, g: 100, b: 0, a };
+            let straight = c.flatten_rgb(bg);
+            let premultiplied = DigitalPremultipliedRGBAColor::from(c).flatten_rgb(bg);
+            assert!((straight.r as i16 - premultiplied.r as i16).abs() <= 1, "{:?} != {:?}", straight, premultiplied);
+            assert!((straight.g as i16 - premultiplied.g as i16).abs() <= 1, "{:?} != {:?}", straight, premultiplied);
+            assert!((straight.b as i16 - premultiplied.b as i16).abs() <= 1, "{:?} != {:?}", straight, premultiplied);
+        }
+    }
+
+    #[test]
+    fn flatten_into_other_types() {
+        let transparent = DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 };
+        let gray: DigitalGrayscaleColor = transparent.flatten(WHITE);
+        assert_eq!(DigitalGrayscaleColor{ v: 255 }, gray);
+        let ycbcr: DigitalYCbCrColor = transparent.flatten(WHITE);
+        assert_eq!(DigitalYCbCrColor::from(WHITE), ycbcr);
+        let lab: CIELabColor = transparent.flatten(WHITE);
+        assert!((lab.l - 100.0).abs() < 0.01, "{:?}", lab);
+    }
+
+    #[test]
+    fn flatten_vec() {
+        let items = vec![
+            DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 0 },
+            DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }
+        ];
+        assert_eq!(
+            vec![DigitalGrayscaleColor{ v: 255 }, DigitalGrayscaleColor{ v: 0 }],
+            DigitalRGBAColor::flatten_vec_grayscale(items.clone(), WHITE)
+        );
+        assert_eq!(
+            vec![WHITE, DigitalRGBColor{ r: 0, g: 0, b: 0 }],
+            DigitalRGBAColor::flatten_vec_rgb(items, WHITE)
+        );
+    }
+
+    #[test]
+    fn rgba_d_2_u32() {
+        let c = DigitalRGBAColor{ r: 0x11, g: 0x22, b: 0x33, a: 0x44 };
+        assert_eq!(0x11223344u32, u32::from(c));
+        assert_eq!(0xff000080u32, u32::from(DigitalRGBAColor{ r: 255, g: 0, b: 0, a: 128 }));
+        assert_eq!(0x000000ffu32, u32::from(DigitalRGBAColor{ r: 0, g: 0, b: 0, a: 255 }));
+        assert_eq!(c, DigitalRGBAColor::from(u32::from(c)));
     }
 }
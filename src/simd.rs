@@ -0,0 +1,568 @@
+use crate::*;
+use crate::premultiplied::mul_u8;
+
+/// Number of pixels that are converted together
+const BLOCK: usize = 8;
+
+/// Red, green and blue (or x, y and z) components of a block of pixels
+type Lanes = [[f32; BLOCK]; 3];
+
+/// Affine transformation, each row computes `((m[0] * a + m[1] * b) + m[2] * c) + m[3]`
+///
+/// The operation order is the one of the scalar `From` implementations so the results are bit-exact.
+/// Rows without offset use `-0.0`, which is the additive identity for all values including `-0.0`.
+type Affine = [[f32; 4]; 3];
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+const RGB_TO_YCBCR: Affine = [
+    [ 0.299,   0.587,   0.114,  -0.0],
+    [-0.1687, -0.3313,  0.5,   128.0],
+    [ 0.5,    -0.4187, -0.0813, 128.0]
+];
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
+const YCBCR_TO_RGB: Affine = [
+    [1.0,  0.0,       1.402,    -0.0],
+    [1.0, -0.344136, -0.714136, -0.0],
+    [1.0,  1.772,     0.0,      -0.0]
+];
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+#[allow(clippy::excessive_precision)]
+const LINEAR_TO_XYZ: Affine = [
+    [0.4124564390896921,   0.357576077643909, 0.18043748326639894, -0.0],
+    [0.21267285140562248,  0.715152155287818, 0.07217499330655958, -0.0],
+    [0.019333895582329317, 0.119192025881303, 0.9503040785363677,  -0.0]
+];
+
+/// using [sRGB conversion matrix](http://www.brucelindbloom.com/index.html?Calc.html)
+#[allow(clippy::excessive_precision)]
+const XYZ_TO_LINEAR: Affine = [
+    [ 3.2404541621141054,  -1.5371385127977166,  -0.4985314095560162, -0.0],
+    [-0.9692660305051868,   1.8760108454466942,   0.04155601753034984, -0.0],
+    [ 0.05564343095911469, -0.20402591351675387,  1.0572251882231791, -0.0]
+];
+
+//
+// Backends
+//
+
+/// Instruction set used for the batch conversions
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Backend {
+    #[cfg_attr(any(target_arch = "x86_64", target_arch = "aarch64"), allow(dead_code))]
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse2,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon
+}
+
+impl Backend {
+    /// Best backend supported by the running CPU
+    pub(crate) fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Backend::Avx2;
+            }
+            Backend::Sse2
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            Backend::Neon
+        }
+
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            Backend::Scalar
+        }
+    }
+
+    fn affine(self) -> unsafe fn(&Affine, &mut Lanes) {
+        match self {
+            Backend::Scalar => affine_scalar,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => x86::affine_sse2,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => x86::affine_avx2,
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => neon::affine_neon
+        }
+    }
+
+    /// Premultiply as many bytes as possible, returns the number of bytes written
+    fn premultiply(self) -> unsafe fn(&[u8], &mut [u8]) -> usize {
+        match self {
+            Backend::Scalar => premultiply_scalar,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => x86::premultiply_sse2,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => x86::premultiply_avx2,
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => neon::premultiply_neon
+        }
+    }
+}
+
+unsafe fn affine_scalar(m: &Affine, lanes: &mut Lanes) {
+    for i in 0..BLOCK {
+        let (a, b, c) = (lanes[0][i], lanes[1][i], lanes[2][i]);
+        for (lane, row) in lanes.iter_mut().zip(m) {
+            lane[i] = a * row[0] + b * row[1] + c * row[2] + row[3];
+        }
+    }
+}
+
+unsafe fn premultiply_scalar(_src: &[u8], _dst: &mut [u8]) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::{Affine, Lanes};
+    use std::arch::x86_64::*;
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn affine_sse2(m: &Affine, lanes: &mut Lanes) {
+        for o in (0..super::BLOCK).step_by(4) {
+            let a = _mm_loadu_ps(lanes[0][o..].as_ptr());
+            let b = _mm_loadu_ps(lanes[1][o..].as_ptr());
+            let c = _mm_loadu_ps(lanes[2][o..].as_ptr());
+
+            let mut out = [_mm_setzero_ps(); 3];
+            for (x, row) in out.iter_mut().zip(m) {
+                let ab = _mm_add_ps(_mm_mul_ps(a, _mm_set1_ps(row[0])), _mm_mul_ps(b, _mm_set1_ps(row[1])));
+                *x = _mm_add_ps(_mm_add_ps(ab, _mm_mul_ps(c, _mm_set1_ps(row[2]))), _mm_set1_ps(row[3]));
+            }
+
+            for (lane, x) in lanes.iter_mut().zip(out) {
+                _mm_storeu_ps(lane[o..].as_mut_ptr(), x);
+            }
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn affine_avx2(m: &Affine, lanes: &mut Lanes) {
+        let a = _mm256_loadu_ps(lanes[0].as_ptr());
+        let b = _mm256_loadu_ps(lanes[1].as_ptr());
+        let c = _mm256_loadu_ps(lanes[2].as_ptr());
+
+        let mut out = [_mm256_setzero_ps(); 3];
+        for (x, row) in out.iter_mut().zip(m) {
+            let ab = _mm256_add_ps(_mm256_mul_ps(a, _mm256_set1_ps(row[0])), _mm256_mul_ps(b, _mm256_set1_ps(row[1])));
+            *x = _mm256_add_ps(_mm256_add_ps(ab, _mm256_mul_ps(c, _mm256_set1_ps(row[2]))), _mm256_set1_ps(row[3]));
+        }
+
+        for (lane, x) in lanes.iter_mut().zip(out) {
+            _mm256_storeu_ps(lane.as_mut_ptr(), x);
+        }
+    }
+
+    /// Premultiply four RGBA pixels widened to `u16`, same rounding as `mul_u8`
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn mul_sse2(p: __m128i) -> __m128i {
+        let alpha_mask = _mm_set1_epi64x(0xffff_0000_0000_0000u64 as i64);
+        let a = _mm_shufflehi_epi16::<0xff>(_mm_shufflelo_epi16::<0xff>(p));
+        let t = _mm_add_epi16(_mm_mullo_epi16(p, a), _mm_set1_epi16(128));
+        let r = _mm_srli_epi16::<8>(_mm_add_epi16(t, _mm_srli_epi16::<8>(t)));
+
+        _mm_or_si128(_mm_andnot_si128(alpha_mask, r), _mm_and_si128(alpha_mask, p))
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn premultiply_sse2(src: &[u8], dst: &mut [u8]) -> usize {
+        let n = src.len().min(dst.len()) / 16 * 16;
+        let zero = _mm_setzero_si128();
+
+        for i in (0..n).step_by(16) {
+            let v = _mm_loadu_si128(src.as_ptr().add(i) as *const __m128i);
+            let lo = mul_sse2(_mm_unpacklo_epi8(v, zero));
+            let hi = mul_sse2(_mm_unpackhi_epi8(v, zero));
+            _mm_storeu_si128(dst.as_mut_ptr().add(i) as *mut __m128i, _mm_packus_epi16(lo, hi));
+        }
+
+        n
+    }
+
+    /// Premultiply eight RGBA pixels widened to `u16`, same rounding as `mul_u8`
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_avx2(p: __m256i) -> __m256i {
+        let alpha_mask = _mm256_set1_epi64x(0xffff_0000_0000_0000u64 as i64);
+        let a = _mm256_shufflehi_epi16::<0xff>(_mm256_shufflelo_epi16::<0xff>(p));
+        let t = _mm256_add_epi16(_mm256_mullo_epi16(p, a), _mm256_set1_epi16(128));
+        let r = _mm256_srli_epi16::<8>(_mm256_add_epi16(t, _mm256_srli_epi16::<8>(t)));
+
+        _mm256_or_si256(_mm256_andnot_si256(alpha_mask, r), _mm256_and_si256(alpha_mask, p))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn premultiply_avx2(src: &[u8], dst: &mut [u8]) -> usize {
+        let n = src.len().min(dst.len()) / 32 * 32;
+        let zero = _mm256_setzero_si256();
+
+        // unpack and pack work per 128 bit lane, so the pixel order is preserved
+        for i in (0..n).step_by(32) {
+            let v = _mm256_loadu_si256(src.as_ptr().add(i) as *const __m256i);
+            let lo = mul_avx2(_mm256_unpacklo_epi8(v, zero));
+            let hi = mul_avx2(_mm256_unpackhi_epi8(v, zero));
+            _mm256_storeu_si256(dst.as_mut_ptr().add(i) as *mut __m256i, _mm256_packus_epi16(lo, hi));
+        }
+
+        n
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::{Affine, Lanes};
+    use std::arch::aarch64::*;
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn affine_neon(m: &Affine, lanes: &mut Lanes) {
+        for o in (0..super::BLOCK).step_by(4) {
+            let a = vld1q_f32(lanes[0][o..].as_ptr());
+            let b = vld1q_f32(lanes[1][o..].as_ptr());
+            let c = vld1q_f32(lanes[2][o..].as_ptr());
+
+            let mut out = [vdupq_n_f32(0.0); 3];
+            for (x, row) in out.iter_mut().zip(m) {
+                let ab = vaddq_f32(vmulq_f32(a, vdupq_n_f32(row[0])), vmulq_f32(b, vdupq_n_f32(row[1])));
+                *x = vaddq_f32(vaddq_f32(ab, vmulq_f32(c, vdupq_n_f32(row[2]))), vdupq_n_f32(row[3]));
+            }
+
+            for (lane, x) in lanes.iter_mut().zip(out) {
+                vst1q_f32(lane[o..].as_mut_ptr(), x);
+            }
+        }
+    }
+
+    /// Premultiply one channel of eight pixels, same rounding as `mul_u8`
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mul_neon(c: uint8x8_t, a: uint8x8_t) -> uint8x8_t {
+        let t = vaddq_u16(vmull_u8(c, a), vdupq_n_u16(128));
+        vshrn_n_u16::<8>(vsraq_n_u16::<8>(t, t))
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn premultiply_neon(src: &[u8], dst: &mut [u8]) -> usize {
+        let n = src.len().min(dst.len()) / 32 * 32;
+
+        for i in (0..n).step_by(32) {
+            let mut v = vld4_u8(src.as_ptr().add(i));
+            v.0 = mul_neon(v.0, v.3);
+            v.1 = mul_neon(v.1, v.3);
+            v.2 = mul_neon(v.2, v.3);
+            vst4_u8(dst.as_mut_ptr().add(i), v);
+        }
+
+        n
+    }
+}
+
+//
+// Block conversion
+//
+
+/// Convert blocks of pixels with an affine transformation between a scalar `load` and `store` step
+#[inline]
+fn map_blocks<S: Copy, D>(backend: Backend, m: &Affine, items: &[S], output: &mut [D], load: impl Fn(S) -> [f32; 3], store: impl Fn([f32; 3]) -> D) {
+    let affine = backend.affine();
+
+    for (src, dst) in items.chunks(BLOCK).zip(output.chunks_mut(BLOCK)) {
+        let mut lanes: Lanes = [[0.0; BLOCK]; 3];
+        for (i, px) in src.iter().enumerate() {
+            let [a, b, c] = load(*px);
+            lanes[0][i] = a;
+            lanes[1][i] = b;
+            lanes[2][i] = c;
+        }
+
+        // SAFETY: the backend has been detected on the running CPU
+        unsafe { affine(m, &mut lanes) };
+
+        for (i, d) in dst.iter_mut().take(src.len()).enumerate() {
+            *d = store([lanes[0][i], lanes[1][i], lanes[2][i]]);
+        }
+    }
+}
+
+//
+// Batch conversions
+//
+
+pub(crate) fn rgb_to_ycbcr(items: &[DigitalRGBColor], output: &mut [DigitalYCbCrColor]) {
+    rgb_to_ycbcr_with(Backend::detect(), items, output)
+}
+
+fn rgb_to_ycbcr_with(backend: Backend, items: &[DigitalRGBColor], output: &mut [DigitalYCbCrColor]) {
+    map_blocks(backend, &RGB_TO_YCBCR, items, output,
+        |c| [c.r as f32, c.g as f32, c.b as f32],
+        |[y, cb, cr]| DigitalYCbCrColor{ y: y as u8, cb: cb as u8, cr: cr as u8 })
+}
+
+pub(crate) fn ycbcr_to_rgb(items: &[DigitalYCbCrColor], output: &mut [DigitalRGBColor]) {
+    ycbcr_to_rgb_with(Backend::detect(), items, output)
+}
+
+fn ycbcr_to_rgb_with(backend: Backend, items: &[DigitalYCbCrColor], output: &mut [DigitalRGBColor]) {
+    map_blocks(backend, &YCBCR_TO_RGB, items, output,
+        |c| [c.y as f32 / 255.0, c.cb as f32 / 255.0 - 0.5, c.cr as f32 / 255.0 - 0.5],
+        |[r, g, b]| DigitalRGBColor{
+            r: (r.min(1.0) * 255.0) as u8,
+            g: (g.min(1.0) * 255.0) as u8,
+            b: (b.min(1.0) * 255.0) as u8
+        })
+}
+
+pub(crate) fn rgb_to_grayscale(items: &[DigitalRGBColor], output: &mut [DigitalGrayscaleColor]) {
+    rgb_to_grayscale_with(Backend::detect(), items, output)
+}
+
+fn rgb_to_grayscale_with(backend: Backend, items: &[DigitalRGBColor], output: &mut [DigitalGrayscaleColor]) {
+    map_blocks(backend, &RGB_TO_YCBCR, items, output,
+        |c| [c.r as f32, c.g as f32, c.b as f32],
+        |[v, _, _]| DigitalGrayscaleColor{ v: v.round().clamp(0.0, 255.0) as u8 })
+}
+
+fn linear_to_xyz<S: Copy, D>(backend: Backend, items: &[S], output: &mut [D], load: impl Fn(S) -> [f32; 3], store: impl Fn(CIEXYZColor) -> D) {
+    map_blocks(backend, &LINEAR_TO_XYZ, items, output, load, |[x, y, z]| store(CIEXYZColor{ x, y, z }))
+}
+
+pub(crate) fn rgb_to_xyz(items: &[DigitalRGBColor], output: &mut [CIEXYZColor]) {
+    linear_to_xyz(Backend::detect(), items, output, srgb_to_linear_rgb, |c| c)
+}
+
+pub(crate) fn rgb_to_lab(items: &[DigitalRGBColor], output: &mut [CIELabColor]) {
+    linear_to_xyz(Backend::detect(), items, output, srgb_to_linear_rgb, CIELabColor::from)
+}
+
+pub(crate) fn normalized_rgb_to_xyz(items: &[NormalizedRGBColor], output: &mut [CIEXYZColor]) {
+    linear_to_xyz(Backend::detect(), items, output, normalized_to_linear_rgb, |c| c)
+}
+
+pub(crate) fn normalized_rgb_to_lab(items: &[NormalizedRGBColor], output: &mut [CIELabColor]) {
+    linear_to_xyz(Backend::detect(), items, output, normalized_to_linear_rgb, CIELabColor::from)
+}
+
+fn xyz_to_linear<S: Copy, D>(backend: Backend, items: &[S], output: &mut [D], load: impl Fn(S) -> CIEXYZColor, store: impl Fn([f32; 3]) -> D) {
+    map_blocks(backend, &XYZ_TO_LINEAR, items, output, |c| { let c = load(c); [c.x, c.y, c.z] }, store)
+}
+
+pub(crate) fn xyz_to_rgb(items: &[CIEXYZColor], output: &mut [DigitalRGBColor], precision: TransferPrecision) {
+    match precision {
+        TransferPrecision::Fast => xyz_to_linear(Backend::detect(), items, output, |c| c, linear_to_digital_rgb_fast),
+        TransferPrecision::Exact => xyz_to_linear(Backend::detect(), items, output, |c| c, linear_to_digital_rgb)
+    }
+}
+
+pub(crate) fn lab_to_rgb(items: &[CIELabColor], output: &mut [DigitalRGBColor], precision: TransferPrecision) {
+    match precision {
+        TransferPrecision::Fast => xyz_to_linear(Backend::detect(), items, output, CIEXYZColor::from, linear_to_digital_rgb_fast),
+        TransferPrecision::Exact => xyz_to_linear(Backend::detect(), items, output, CIEXYZColor::from, linear_to_digital_rgb)
+    }
+}
+
+pub(crate) fn premultiply(items: &[DigitalRGBAColor], output: &mut [DigitalPremultipliedRGBAColor]) {
+    premultiply_with(Backend::detect(), items, output)
+}
+
+fn premultiply_with(backend: Backend, items: &[DigitalRGBAColor], output: &mut [DigitalPremultipliedRGBAColor]) {
+    let n = items.len().min(output.len());
+    let (items, output) = (&items[..n], &mut output[..n]);
+
+    // SAFETY: the backend has been detected on the running CPU
+    let done = unsafe { backend.premultiply()(DigitalRGBAColor::as_bytes(items), DigitalPremultipliedRGBAColor::as_bytes_mut(output)) } / 4;
+
+    for (o, c) in output[done..].iter_mut().zip(&items[done..]) {
+        *o = DigitalPremultipliedRGBAColor{ r: mul_u8(c.r, c.a), g: mul_u8(c.g, c.a), b: mul_u8(c.b, c.a), a: c.a };
+    }
+}
+
+#[inline]
+fn srgb_to_linear_rgb(c: DigitalRGBColor) -> [f32; 3] {
+    [srgb_to_linear_u8(c.r), srgb_to_linear_u8(c.g), srgb_to_linear_u8(c.b)]
+}
+
+#[inline]
+fn normalized_to_linear_rgb(c: NormalizedRGBColor) -> [f32; 3] {
+    [srgb_to_linear(c.r), srgb_to_linear(c.g), srgb_to_linear(c.b)]
+}
+
+#[inline]
+fn linear_to_digital_rgb(c: [f32; 3]) -> DigitalRGBColor {
+    let map = |c: f32| (linear_to_srgb(c) * 255.0).round().clamp(0.0, 255.0) as u8;
+    DigitalRGBColor{ r: map(c[0]), g: map(c[1]), b: map(c[2]) }
+}
+
+#[inline]
+fn linear_to_digital_rgb_fast(c: [f32; 3]) -> DigitalRGBColor {
+    DigitalRGBColor{ r: linear_to_srgb_u8(c[0]), g: linear_to_srgb_u8(c[1]), b: linear_to_srgb_u8(c[2]) }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backends() -> Vec<Backend> {
+        #[allow(unused_mut)]
+        let mut backends = vec![Backend::Scalar];
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            backends.push(Backend::Sse2);
+            if is_x86_feature_detected!("avx2") {
+                backends.push(Backend::Avx2);
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        backends.push(Backend::Neon);
+
+        backends
+    }
+
+    /// RGB samples with a length that is not a multiple of the block size
+    fn rgb_samples() -> Vec<DigitalRGBColor> {
+        let mut items = Vec::new();
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(7) {
+                for b in (0..=255).step_by(3) {
+                    items.push(DigitalRGBColor{ r, g, b });
+                }
+            }
+        }
+        items.push(DigitalRGBColor{ r: 255, g: 255, b: 255 });
+        items
+    }
+
+    fn xyz_samples() -> Vec<CIEXYZColor> {
+        let mut items = Vec::new();
+        for x in 0..=23 {
+            for y in 0..=23 {
+                for z in 0..=23 {
+                    items.push(CIEXYZColor{ x: x as f32 / 20.0 - 0.05, y: y as f32 / 20.0 - 0.05, z: z as f32 / 20.0 - 0.05 });
+                }
+            }
+        }
+        items
+    }
+
+    #[test]
+    fn rgb_d_2_ycbcr_d() {
+        let items = rgb_samples();
+        for backend in backends() {
+            let mut output = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; items.len()];
+            rgb_to_ycbcr_with(backend, &items, &mut output);
+            for (c, o) in items.iter().zip(&output) {
+                assert_eq!(DigitalYCbCrColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn ycbcr_d_2_rgb_d() {
+        let items: Vec<DigitalYCbCrColor> = rgb_samples().into_iter().map(|c| DigitalYCbCrColor{ y: c.r, cb: c.g, cr: c.b }).collect();
+        for backend in backends() {
+            let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+            ycbcr_to_rgb_with(backend, &items, &mut output);
+            for (c, o) in items.iter().zip(&output) {
+                assert_eq!(DigitalRGBColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_d_2_gray_d() {
+        let items = rgb_samples();
+        for backend in backends() {
+            let mut output = vec![DigitalGrayscaleColor{ v: 0 }; items.len()];
+            rgb_to_grayscale_with(backend, &items, &mut output);
+            for (c, o) in items.iter().zip(&output) {
+                assert_eq!(DigitalGrayscaleColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_2_xyz_and_lab() {
+        let items = rgb_samples();
+        let normalized: Vec<NormalizedRGBColor> = items.iter().map(|c| NormalizedRGBColor::from(*c)).collect();
+        for backend in backends() {
+            let mut xyz = vec![CIEXYZColor{ x: 0.0, y: 0.0, z: 0.0 }; items.len()];
+            let mut lab = vec![CIELabColor{ l: 0.0, a: 0.0, b: 0.0 }; items.len()];
+            linear_to_xyz(backend, &items, &mut xyz, srgb_to_linear_rgb, |c| c);
+            linear_to_xyz(backend, &items, &mut lab, srgb_to_linear_rgb, CIELabColor::from);
+            for ((c, x), l) in items.iter().zip(&xyz).zip(&lab) {
+                assert_eq!(CIEXYZColor::from(*c), *x, "{:?} {:?}", backend, c);
+                assert_eq!(CIELabColor::from(*c), *l, "{:?} {:?}", backend, c);
+            }
+
+            linear_to_xyz(backend, &normalized, &mut xyz, normalized_to_linear_rgb, |c| c);
+            for (c, x) in normalized.iter().zip(&xyz) {
+                assert_eq!(CIEXYZColor::from(*c), *x, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn xyz_and_lab_2_rgb() {
+        let items = xyz_samples();
+        let lab: Vec<CIELabColor> = items.iter().map(|c| CIELabColor::from(*c)).collect();
+        for backend in backends() {
+            let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+            xyz_to_linear(backend, &items, &mut output, |c| c, linear_to_digital_rgb);
+            for (c, o) in items.iter().zip(&output) {
+                assert_eq!(DigitalRGBColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+
+            xyz_to_linear(backend, &lab, &mut output, CIEXYZColor::from, linear_to_digital_rgb);
+            for (c, o) in lab.iter().zip(&output) {
+                assert_eq!(DigitalRGBColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn rgba_d_2_premultiplied_d() {
+        let mut items = Vec::new();
+        for a in 0..=255 {
+            for c in 0..=255 {
+                items.push(DigitalRGBAColor{ r: c, g: 255 - c, b: c / 2, a });
+            }
+        }
+        items.push(DigitalRGBAColor{ r: 200, g: 100, b: 50, a: 77 });
+
+        for backend in backends() {
+            let mut output = vec![DigitalPremultipliedRGBAColor{ r: 0, g: 0, b: 0, a: 0 }; items.len()];
+            premultiply_with(backend, &items, &mut output);
+            for (c, o) in items.iter().zip(&output) {
+                assert_eq!(DigitalPremultipliedRGBAColor::from(*c), *o, "{:?} {:?}", backend, c);
+            }
+        }
+    }
+
+    #[test]
+    fn convertible_uses_simd() {
+        let items = rgb_samples();
+        let expected: Vec<DigitalYCbCrColor> = items.iter().map(|c| DigitalYCbCrColor::from(*c)).collect();
+        assert_eq!(expected, DigitalRGBColor::convert_vec_ycbcr(items.clone()));
+
+        let mut output = vec![DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }; items.len() + 1];
+        DigitalRGBColor::convert_slice_ycbcr(&items, &mut output).unwrap();
+        assert_eq!(expected, output[..items.len()]);
+        assert_eq!(DigitalYCbCrColor{ y: 0, cb: 0, cr: 0 }, output[items.len()]);
+        output.pop();
+        assert_eq!(Err(ColorConversionError::BufferTooSmall), DigitalRGBColor::convert_slice_ycbcr(&items, &mut output[1..]));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::*;
+use crate::matrix::{self, Matrix3};
+
+//
+// White points
+//
+
+impl WhitePoint {
+    /// XYZ tristimulus values of the white point, normalized to `y = 1.0`
+    ///
+    /// Standard illuminants use the [ASTM E308-01 values](http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html)
+    pub fn xyz(self) -> CIEXYZColor {
+        let [x, y, z] = self.xyz_f64();
+
+        CIEXYZColor{ x: x as f32, y: y as f32, z: z as f32 }
+    }
+
+    /// XYZ tristimulus values of the white point in `[x, y, z]` order with `f64` precision, normalized to `y = 1.0`
+    pub fn xyz_f64(self) -> [f64; 3] {
+        let (x, z) = match self {
+            WhitePoint::A => (1.09850, 0.35585),
+            WhitePoint::D50 => (0.96422, 0.82521),
+            WhitePoint::D55 => (0.95682, 0.92149),
+            WhitePoint::D65 => (0.95047, 1.08883),
+            WhitePoint::D75 => (0.94972, 1.22638),
+            WhitePoint::E => (1.0, 1.0),
+            WhitePoint::F2 => (0.99187, 0.67395),
+            WhitePoint::F7 => (0.95044, 1.08755),
+            WhitePoint::F11 => (1.00966, 0.64370),
+            WhitePoint::Custom{ x, y } => {
+                let (x, y) = (x as f64, y as f64);
+                (x / y, (1.0 - x - y) / y)
+            }
+        };
+
+        [x, 1.0, z]
+    }
+}
+
+//
+// Chromatic adaptation
+//
+
+impl ChromaticAdaptation {
+    /// Calculate the matrix that adapts XYZ values relative to the white point `from`
+    /// to XYZ values relative to the white point `to`
+    ///
+    /// using the [chromatic adaptation method](http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html)
+    pub fn matrix(self, from: WhitePoint, to: WhitePoint) -> [[f32; 3]; 3] {
+        if from == to {
+            return matrix::IDENTITY;
+        }
+
+        let cone = self.cone_response();
+        let source = from.xyz();
+        let destination = to.xyz();
+        let s = matrix::mul_vec(&cone, [source.x, source.y, source.z]);
+        let d = matrix::mul_vec(&cone, [destination.x, destination.y, destination.z]);
+        let scale = matrix::diagonal([d[0] / s[0], d[1] / s[1], d[2] / s[2]]);
+
+        matrix::mul(&matrix::invert(&cone), &matrix::mul(&scale, &cone))
+    }
+
+    /// Matrix that converts XYZ values into the cone response domain
+    fn cone_response(self) -> Matrix3 {
+        match self {
+            ChromaticAdaptation::Bradford => [
+                [ 0.8951,  0.2664, -0.1614],
+                [-0.7502,  1.7135,  0.0367],
+                [ 0.0389, -0.0685,  1.0296]
+            ],
+            ChromaticAdaptation::VonKries => [
+                [ 0.40024, 0.70760, -0.08081],
+                [-0.22630, 1.16532,  0.04570],
+                [ 0.0,     0.0,      0.91822]
+            ],
+            ChromaticAdaptation::CAT02 => [
+                [ 0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975,  0.0061],
+                [ 0.0030, 0.0136,  0.9834]
+            ],
+            ChromaticAdaptation::XYZScaling => matrix::IDENTITY
+        }
+    }
+}
+
+impl CIEXYZColor {
+    /// Adapt a color relative to the white point `from` to the white point `to`
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint, method: ChromaticAdaptation) -> Self {
+        let v = matrix::mul_vec(&method.matrix(from, to), [self.x, self.y, self.z]);
+
+        CIEXYZColor{
+            x: v[0],
+            y: v[1],
+            z: v[2]
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn assert_xyz_eq(a: CIEXYZColor, b: CIEXYZColor) {
+        assert!((a.x - b.x).abs() < 1e-4, "{:?} != {:?}", a, b);
+        assert!((a.y - b.y).abs() < 1e-4, "{:?} != {:?}", a, b);
+        assert!((a.z - b.z).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn custom_white_point() {
+        let d65 = WhitePoint::Custom{ x: 0.31271, y: 0.32902 };
+        assert_xyz_eq(WhitePoint::D65.xyz(), d65.xyz());
+    }
+
+    #[test]
+    fn bradford_d65_2_d50() {
+        let m = ChromaticAdaptation::Bradford.matrix(WhitePoint::D65, WhitePoint::D50);
+        let expected = [
+            [ 1.0478112,  0.0228866, -0.0501270],
+            [ 0.0295424,  0.9904844, -0.0170491],
+            [-0.0092345,  0.0150436,  0.7521316]
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((m[i][j] - expected[i][j]).abs() < 1e-5, "{:?} != {:?}", m, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn adapt_white() {
+        let methods = [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::CAT02,
+            ChromaticAdaptation::XYZScaling
+        ];
+        for method in methods.iter() {
+            let white = WhitePoint::D65.xyz().adapt(WhitePoint::D65, WhitePoint::D50, *method);
+            assert_xyz_eq(WhitePoint::D50.xyz(), white);
+            let white = WhitePoint::A.xyz().adapt(WhitePoint::A, WhitePoint::F7, *method);
+            assert_xyz_eq(WhitePoint::F7.xyz(), white);
+        }
+    }
+
+    #[test]
+    fn adapt_roundtrip() {
+        let c = CIEXYZColor{ x: 0.3, y: 0.4, z: 0.5 };
+        let d50 = c.adapt(WhitePoint::D65, WhitePoint::D50, ChromaticAdaptation::CAT02);
+        assert_xyz_eq(c, d50.adapt(WhitePoint::D50, WhitePoint::D65, ChromaticAdaptation::CAT02));
+    }
+}
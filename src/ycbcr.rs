@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 use crate::*;
+use crate::fixed::{self, FixedYCbCr};
 
 //
 // Native types to YCbCr
@@ -92,41 +93,127 @@ impl From<u32> for NormalizedYCbCrColor {
     }
 }
 
-impl Into<[u8; 3]> for DigitalYCbCrColor {
-    fn into(self) -> [u8; 3] {
-        [self.y, self.cb, self.cr]
+impl From<DigitalYCbCrColor> for [u8; 3] {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        [f.y, f.cb, f.cr]
     }
 }
 
-impl Into<[u8; 4]> for DigitalYCbCrColor {
-    fn into(self) -> [u8; 4] {
-        [self.y, self.cb, self.cr, 255]
+impl From<DigitalYCbCrColor> for [u8; 4] {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        [f.y, f.cb, f.cr, 255]
     }
 }
 
-impl Into<u32> for DigitalYCbCrColor {
-    fn into(self) -> u32 {
-        (self.y as u32) << 24 +
-        (self.cb as u32) << 16 +
-        (self.cr as u32) << 8
+impl From<DigitalYCbCrColor> for u32 {
+    fn from(f: DigitalYCbCrColor) -> Self {
+        (f.y as u32) << 24 |
+        (f.cb as u32) << 16 |
+        (f.cr as u32) << 8
     }
 }
 
-impl Into<[u8; 3]> for NormalizedYCbCrColor {
-    fn into(self) -> [u8; 3] {
-        DigitalYCbCrColor::from(self).into()
+impl From<NormalizedYCbCrColor> for [u8; 3] {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalYCbCrColor::from(f).into()
     }
 }
 
-impl Into<[u8; 4]> for NormalizedYCbCrColor {
-    fn into(self) -> [u8; 4] {
-        DigitalYCbCrColor::from(self).into()
+impl From<NormalizedYCbCrColor> for [u8; 4] {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalYCbCrColor::from(f).into()
     }
 }
 
-impl Into<u32> for NormalizedYCbCrColor {
-    fn into(self) -> u32 {
-        DigitalYCbCrColor::from(self).into()
+impl From<NormalizedYCbCrColor> for u32 {
+    fn from(f: NormalizedYCbCrColor) -> Self {
+        DigitalYCbCrColor::from(f).into()
+    }
+}
+
+//
+// Matrix coefficients
+//
+
+impl YCbCrMatrix {
+    /// luma coefficient of the red component
+    pub fn kr(self) -> f32 {
+        match self {
+            YCbCrMatrix::BT601 => 0.299,
+            YCbCrMatrix::BT709 => 0.2126,
+            YCbCrMatrix::BT2020 => 0.2627,
+            YCbCrMatrix::SMPTE240M => 0.212,
+            YCbCrMatrix::Custom{ kr, .. } => kr
+        }
+    }
+
+    /// luma coefficient of the green component
+    pub fn kg(self) -> f32 {
+        1.0 - self.kr() - self.kb()
+    }
+
+    /// luma coefficient of the blue component
+    pub fn kb(self) -> f32 {
+        match self {
+            YCbCrMatrix::BT601 => 0.114,
+            YCbCrMatrix::BT709 => 0.0722,
+            YCbCrMatrix::BT2020 => 0.0593,
+            YCbCrMatrix::SMPTE240M => 0.087,
+            YCbCrMatrix::Custom{ kb, .. } => kb
+        }
+    }
+}
+
+//
+// Quantization ranges
+//
+
+impl YCbCrRange {
+    /// Quantize a normalized YCbCr color to integer components of `bit_depth` bits
+    ///
+    /// Returns the components in `[y, cb, cr]` order, values that are out of range are clipped.
+    /// Panics if `bit_depth` is not in the range of 8 to 16.
+    pub fn quantize(self, f: NormalizedYCbCrColor, bit_depth: u32) -> [u16; 3] {
+        let max = ((1u32 << bit_depth) - 1) as f32;
+        let (y_scale, y_offset, c_scale) = self.scale(bit_depth);
+        let c_offset = (1u32 << (bit_depth - 1)) as f32;
+
+        [
+            (f.y * y_scale + y_offset).round().clamp(0.0, max) as u16,
+            (f.cb * c_scale + c_offset).round().clamp(0.0, max) as u16,
+            (f.cr * c_scale + c_offset).round().clamp(0.0, max) as u16
+        ]
+    }
+
+    /// Convert integer YCbCr components of `bit_depth` bits in `[y, cb, cr]` order to a normalized color
+    ///
+    /// Panics if `bit_depth` is not in the range of 8 to 16.
+    pub fn dequantize(self, v: [u16; 3], bit_depth: u32) -> NormalizedYCbCrColor {
+        let (y_scale, y_offset, c_scale) = self.scale(bit_depth);
+        let c_offset = (1u32 << (bit_depth - 1)) as f32;
+
+        NormalizedYCbCrColor{
+            y: (v[0] as f32 - y_offset) / y_scale,
+            cb: (v[1] as f32 - c_offset) / c_scale,
+            cr: (v[2] as f32 - c_offset) / c_scale
+        }
+    }
+
+    /// Scale of the luma component, offset of the luma component and scale of the chroma components
+    #[inline]
+    fn scale(self, bit_depth: u32) -> (f32, f32, f32) {
+        assert!((8..=16).contains(&bit_depth), "bit depth {} is not in the range of 8 to 16", bit_depth);
+
+        match self {
+            YCbCrRange::Full => {
+                let max = ((1u32 << bit_depth) - 1) as f32;
+                (max, 0.0, max)
+            },
+            YCbCrRange::Limited => {
+                let factor = (1u32 << (bit_depth - 8)) as f32;
+                (219.0 * factor, 16.0 * factor, 224.0 * factor)
+            }
+        }
     }
 }
 
@@ -134,6 +221,26 @@ impl Into<u32> for NormalizedYCbCrColor {
 // YCbCr to YCbCr
 //
 
+impl DigitalYCbCrColor {
+    /// Quantize a normalized color to `range`
+    pub fn from_normalized(f: NormalizedYCbCrColor, range: YCbCrRange) -> Self {
+        let v = range.quantize(f, 8);
+
+        DigitalYCbCrColor{
+            y: v[0] as u8,
+            cb: v[1] as u8,
+            cr: v[2] as u8
+        }
+    }
+}
+
+impl NormalizedYCbCrColor {
+    /// Convert a color that has been quantized to `range`
+    pub fn from_digital(f: DigitalYCbCrColor, range: YCbCrRange) -> Self {
+        range.dequantize([f.y as u16, f.cb as u16, f.cr as u16], 8)
+    }
+}
+
 impl From<DigitalYCbCrColor> for NormalizedYCbCrColor {
     fn from(f: DigitalYCbCrColor) -> Self {
         NormalizedYCbCrColor{
@@ -203,6 +310,71 @@ impl From<NormalizedGrayscaleColor> for NormalizedYCbCrColor {
 // RGB to YCbCr
 //
 
+impl NormalizedYCbCrColor {
+    /// Convert a RGB color to YCbCr using the coefficients of `matrix`
+    pub fn from_rgb(f: NormalizedRGBColor, matrix: YCbCrMatrix) -> Self {
+        let kr = matrix.kr();
+        let kb = matrix.kb();
+        let y = kr * f.r + matrix.kg() * f.g + kb * f.b;
+
+        NormalizedYCbCrColor{
+            y,
+            cb: (f.b - y) / (2.0 * (1.0 - kb)),
+            cr: (f.r - y) / (2.0 * (1.0 - kr))
+        }
+    }
+}
+
+impl DigitalYCbCrColor {
+    /// Convert a RGB color to YCbCr using the coefficients of `matrix`, quantized to `range`
+    pub fn from_rgb(f: DigitalRGBColor, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        DigitalYCbCrColor::from_normalized(
+            NormalizedYCbCrColor::from_rgb(NormalizedRGBColor::from(f), matrix),
+            range
+        )
+    }
+
+    /// Convert a RGB color to YCbCr using 16 bit fixed-point coefficients of `matrix`, quantized to `range`
+    ///
+    /// The result is bit-exact with libjpeg for `BT601` in full range and may differ by one from `from_rgb`.
+    pub fn from_rgb_fixed(f: DigitalRGBColor, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        FixedYCbCr::new(matrix, range).encode(f)
+    }
+
+    /// Convert a vector of YCbCr colors quantized to `range` into a vector of `DigitalRGBColor` values
+    /// using the coefficients of `matrix` and `arithmetic`
+    pub fn convert_vec_rgb_with(items: Vec<Self>, matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Vec<DigitalRGBColor> {
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        fixed::ycbcr_to_rgb(&items, &mut output, matrix, range, arithmetic);
+        output
+    }
+
+    /// Convert a slice of YCbCr colors quantized to `range` into a buffer of `DigitalRGBColor` values
+    /// using the coefficients of `matrix` and `arithmetic`
+    pub fn convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        fixed::ycbcr_to_rgb(items, output, matrix, range, arithmetic);
+        Ok(())
+    }
+
+    /// Convert a vector of YCbCr colors quantized to `range` into a vector of `DigitalRGBColor` values
+    /// using the coefficients of `matrix` and `arithmetic` on multiple threads
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_vec_rgb_with(items: Vec<Self>, matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Vec<DigitalRGBColor> {
+        parallel::convert_vec(items, |i, o| Self::convert_slice_rgb_with(i, o, matrix, range, arithmetic))
+    }
+
+    /// Convert a slice of YCbCr colors quantized to `range` into a buffer of `DigitalRGBColor` values
+    /// using the coefficients of `matrix` and `arithmetic` on multiple threads
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_slice_rgb_with(items: &[Self], output: &mut [DigitalRGBColor], matrix: YCbCrMatrix, range: YCbCrRange, arithmetic: YCbCrArithmetic) -> Result<(), ColorConversionError> {
+        parallel::convert_slice(items, output, |i, o| Self::convert_slice_rgb_with(i, o, matrix, range, arithmetic))
+    }
+}
+
 /// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf)
 impl From<DigitalRGBColor> for NormalizedYCbCrColor {
     fn from(f: DigitalRGBColor) -> Self {
@@ -309,68 +481,316 @@ impl From<CIELabColor> for NormalizedYCbCrColor {
 }
 
 
+//
+// HSL to YCbCr
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<NormalizedHSLColor> for NormalizedYCbCrColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        NormalizedYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<DigitalHSLColor> for NormalizedYCbCrColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        NormalizedYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<NormalizedHSLColor> for DigitalYCbCrColor {
+    fn from(f: NormalizedHSLColor) -> Self {
+        DigitalYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<DigitalHSLColor> for DigitalYCbCrColor {
+    fn from(f: DigitalHSLColor) -> Self {
+        DigitalYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// HSV to YCbCr
+//
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<NormalizedHSVColor> for NormalizedYCbCrColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        NormalizedYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<DigitalHSVColor> for NormalizedYCbCrColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        NormalizedYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<NormalizedHSVColor> for DigitalYCbCrColor {
+    fn from(f: NormalizedHSVColor) -> Self {
+        DigitalYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using [JFIF/JPEG conversion](https://www.w3.org/Graphics/JPEG/jfif3.pdf) for conversion from RGB
+impl From<DigitalHSVColor> for DigitalYCbCrColor {
+    fn from(f: DigitalHSVColor) -> Self {
+        DigitalYCbCrColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// YCbCr16 to YCbCr
+//
+
+impl NormalizedYCbCrColor {
+    /// Convert a color that has been quantized to `bit_depth` bits in `range`
+    ///
+    /// Panics if `bit_depth` is not in the range of 8 to 16.
+    pub fn from_digital16(f: DigitalYCbCr16Color, range: YCbCrRange, bit_depth: u32) -> Self {
+        range.dequantize([f.y, f.cb, f.cr], bit_depth)
+    }
+}
+
+impl From<DigitalYCbCr16Color> for NormalizedYCbCrColor {
+    fn from(f: DigitalYCbCr16Color) -> Self {
+        NormalizedYCbCrColor::from_digital16(f, YCbCrRange::Full, 16)
+    }
+}
+
+impl From<DigitalYCbCr16Color> for DigitalYCbCrColor {
+    fn from(f: DigitalYCbCr16Color) -> Self {
+        DigitalYCbCrColor::from(NormalizedYCbCrColor::from(f))
+    }
+}
+
 // 
 // Color conversion traits
 //
 
 impl YCbCrConvertible for NormalizedYCbCrColor {
     fn convert_vec_ycbcr(items: Vec<Self>) -> Vec<DigitalYCbCrColor> {
-        items.into_iter().map(|x| DigitalYCbCrColor::from(x)).collect()
+        items.into_iter().map(DigitalYCbCrColor::from).collect()
     }
 }
 
 impl RGBConvertible for DigitalYCbCrColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        let mut output = vec![DigitalRGBColor{ r: 0, g: 0, b: 0 }; items.len()];
+        simd::ycbcr_to_rgb(&items, &mut output);
+        output
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+
+    fn convert_slice_rgb(items: &[Self], output: &mut [DigitalRGBColor]) -> Result<(), ColorConversionError> {
+        if output.len() < items.len() {
+            return Err(ColorConversionError::BufferTooSmall);
+        }
+
+        simd::ycbcr_to_rgb(items, output);
+        Ok(())
     }
 }
 
 impl RGBConvertible for NormalizedYCbCrColor {
     fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
-        items.into_iter().map(|x| DigitalRGBColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBColor::from).collect()
     }
 
     fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
-        Box::new(items.map(|x| DigitalRGBColor::from(x)))
+        Box::new(items.map(DigitalRGBColor::from))
     }
 }
 
 impl RGBAConvertible for DigitalYCbCrColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl RGBAConvertible for NormalizedYCbCrColor {
     fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
-        items.into_iter().map(|x| DigitalRGBAColor::from(x)).collect()
+        items.into_iter().map(DigitalRGBAColor::from).collect()
     }
 }
 
 impl CIELabConvertible for DigitalYCbCrColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIELabConvertible for NormalizedYCbCrColor {
     fn convert_vec_lab(items: Vec<Self>) -> Vec<CIELabColor> {
-        items.into_iter().map(|x| CIELabColor::from(x)).collect()
+        items.into_iter().map(CIELabColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for DigitalYCbCrColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
     }
 }
 
 impl CIEXYZConvertible for NormalizedYCbCrColor {
     fn convert_vec_xyz(items: Vec<Self>) -> Vec<CIEXYZColor> {
-        items.into_iter().map(|x| CIEXYZColor::from(x)).collect()
+        items.into_iter().map(CIEXYZColor::from).collect()
+    }
+}
+
+impl HSLConvertible for NormalizedYCbCrColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for NormalizedYCbCrColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl HSLConvertible for DigitalYCbCrColor {
+    fn convert_vec_hsl(items: Vec<Self>) -> Vec<DigitalHSLColor> {
+        items.into_iter().map(DigitalHSLColor::from).collect()
+    }
+
+    fn convert_iter_hsl(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSLColor>> {
+        Box::new(items.map(DigitalHSLColor::from))
+    }
+}
+
+impl HSVConvertible for DigitalYCbCrColor {
+    fn convert_vec_hsv(items: Vec<Self>) -> Vec<DigitalHSVColor> {
+        items.into_iter().map(DigitalHSVColor::from).collect()
+    }
+
+    fn convert_iter_hsv(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalHSVColor>> {
+        Box::new(items.map(DigitalHSVColor::from))
+    }
+}
+
+impl YCbCr16Convertible for DigitalYCbCrColor {
+    fn convert_vec_ycbcr16(items: Vec<Self>) -> Vec<DigitalYCbCr16Color> {
+        items.into_iter().map(DigitalYCbCr16Color::from).collect()
+    }
+}
+
+impl YCbCr16Convertible for NormalizedYCbCrColor {
+    fn convert_vec_ycbcr16(items: Vec<Self>) -> Vec<DigitalYCbCr16Color> {
+        items.into_iter().map(DigitalYCbCr16Color::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rgb_n_2_ycbcr_n_bt709() {
+        let r = NormalizedRGBColor{ r: 1.0, g: 0.0, b: 0.0 };
+        let y = NormalizedYCbCrColor::from_rgb(r, YCbCrMatrix::BT709);
+        assert!((y.y - 0.2126).abs() < 1e-6);
+        assert!((y.cb + 0.1146).abs() < 1e-4);
+        assert!((y.cr - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rgb_d_2_ycbcr_d_bt709() {
+        let r = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+        let y = DigitalYCbCrColor::from_rgb(r, YCbCrMatrix::BT709, YCbCrRange::Full);
+        assert_eq!(DigitalYCbCrColor{ y: 255, cb: 128, cr: 128 }, y);
+    }
+
+    #[test]
+    fn custom_matrix_equals_named() {
+        let r = NormalizedRGBColor{ r: 0.2, g: 0.7, b: 0.4 };
+        assert_eq!(
+            NormalizedYCbCrColor::from_rgb(r, YCbCrMatrix::BT2020),
+            NormalizedYCbCrColor::from_rgb(r, YCbCrMatrix::Custom{ kr: 0.2627, kb: 0.0593 })
+        );
+    }
+
+    #[test]
+    fn ycbcr_d_roundtrip_all_matrices() {
+        let matrices = [
+            YCbCrMatrix::BT601,
+            YCbCrMatrix::BT709,
+            YCbCrMatrix::BT2020,
+            YCbCrMatrix::SMPTE240M
+        ];
+        for matrix in matrices.iter() {
+            for v in (0..=255).step_by(15) {
+                let rgb = DigitalRGBColor{ r: v, g: 255 - v, b: v / 2 };
+                let ycbcr = DigitalYCbCrColor::from_rgb(rgb, *matrix, YCbCrRange::Full);
+                let back = DigitalRGBColor::from_ycbcr(ycbcr, *matrix, YCbCrRange::Full);
+                assert!((back.r as i16 - rgb.r as i16).abs() <= 1, "{:?}: {:?} != {:?}", matrix, back, rgb);
+                assert!((back.g as i16 - rgb.g as i16).abs() <= 1, "{:?}: {:?} != {:?}", matrix, back, rgb);
+                assert!((back.b as i16 - rgb.b as i16).abs() <= 1, "{:?}: {:?} != {:?}", matrix, back, rgb);
+            }
+        }
+    }
+
+    #[test]
+    fn ycbcr_n_2_ycbcr_d_limited() {
+        let white = NormalizedYCbCrColor{ y: 1.0, cb: 0.5, cr: -0.5 };
+        let d = DigitalYCbCrColor::from_normalized(white, YCbCrRange::Limited);
+        assert_eq!(DigitalYCbCrColor{ y: 235, cb: 240, cr: 16 }, d);
+        assert_eq!(white, NormalizedYCbCrColor::from_digital(d, YCbCrRange::Limited));
+    }
+
+    #[test]
+    fn ycbcr_quantize_10bit_limited() {
+        let black = NormalizedYCbCrColor{ y: 0.0, cb: 0.0, cr: 0.0 };
+        let white = NormalizedYCbCrColor{ y: 1.0, cb: 0.5, cr: -0.5 };
+        assert_eq!([64, 512, 512], YCbCrRange::Limited.quantize(black, 10));
+        assert_eq!([940, 960, 64], YCbCrRange::Limited.quantize(white, 10));
+        assert_eq!([1023, 1023, 1], YCbCrRange::Full.quantize(white, 10));
+        assert_eq!(white, YCbCrRange::Limited.dequantize([940, 960, 64], 10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ycbcr_quantize_bit_depth_too_small() {
+        YCbCrRange::Limited.quantize(NormalizedYCbCrColor{ y: 0.0, cb: 0.0, cr: 0.0 }, 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ycbcr_dequantize_bit_depth_too_large() {
+        YCbCrRange::Full.dequantize([0, 0, 0], 17);
+    }
+
+    #[test]
+    fn rgb_d_2_ycbcr_d_limited() {
+        let black = DigitalRGBColor{ r: 0, g: 0, b: 0 };
+        let white = DigitalRGBColor{ r: 255, g: 255, b: 255 };
+        let yb = DigitalYCbCrColor::from_rgb(black, YCbCrMatrix::BT709, YCbCrRange::Limited);
+        let yw = DigitalYCbCrColor::from_rgb(white, YCbCrMatrix::BT709, YCbCrRange::Limited);
+        assert_eq!(DigitalYCbCrColor{ y: 16, cb: 128, cr: 128 }, yb);
+        assert_eq!(DigitalYCbCrColor{ y: 235, cb: 128, cr: 128 }, yw);
+        assert_eq!(black, DigitalRGBColor::from_ycbcr(yb, YCbCrMatrix::BT709, YCbCrRange::Limited));
+        assert_eq!(white, DigitalRGBColor::from_ycbcr(yw, YCbCrMatrix::BT709, YCbCrRange::Limited));
     }
 }
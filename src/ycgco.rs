@@ -0,0 +1,304 @@
+use crate::*;
+
+//
+// Native types to YCgCo
+//
+
+impl From<[u8; 3]> for DigitalYCgCoColor {
+    fn from(octets: [u8; 3]) -> Self {
+        DigitalYCgCoColor{
+            y: octets[0],
+            cg: octets[1],
+            co: octets[2]
+        }
+    }
+}
+
+impl From<&[u8; 3]> for DigitalYCgCoColor {
+    fn from(octets: &[u8; 3]) -> Self {
+        DigitalYCgCoColor{
+            y: octets[0],
+            cg: octets[1],
+            co: octets[2]
+        }
+    }
+}
+
+impl From<DigitalYCgCoColor> for [u8; 3] {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        [f.y, f.cg, f.co]
+    }
+}
+
+//
+// YCgCo to YCgCo
+//
+
+impl From<NormalizedYCgCoColor> for DigitalYCgCoColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        DigitalYCgCoColor{
+            y: (f.y * 255.0).round().clamp(0.0, 255.0) as u8,
+            cg: (f.cg * 255.0 + 128.0).round().clamp(0.0, 255.0) as u8,
+            co: (f.co * 255.0 + 128.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl From<DigitalYCgCoColor> for NormalizedYCgCoColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        NormalizedYCgCoColor{
+            y: f.y as f32 / 255.0,
+            cg: (f.cg as f32 - 128.0) / 255.0,
+            co: (f.co as f32 - 128.0) / 255.0
+        }
+    }
+}
+
+impl From<DigitalYCoCgRColor> for NormalizedYCgCoColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        NormalizedYCgCoColor::from(NormalizedRGBColor::from(DigitalRGBColor::from(f)))
+    }
+}
+
+impl From<DigitalYCoCgRColor> for DigitalYCgCoColor {
+    fn from(f: DigitalYCoCgRColor) -> Self {
+        DigitalYCgCoColor::from(NormalizedYCgCoColor::from(f))
+    }
+}
+
+impl From<NormalizedYCgCoColor> for DigitalYCoCgRColor {
+    fn from(f: NormalizedYCgCoColor) -> Self {
+        DigitalYCoCgRColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+impl From<DigitalYCgCoColor> for DigitalYCoCgRColor {
+    fn from(f: DigitalYCgCoColor) -> Self {
+        DigitalYCoCgRColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// RGB to YCgCo
+//
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedRGBColor> for NormalizedYCgCoColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        NormalizedYCgCoColor{
+            y:  0.25 * f.r + 0.5 * f.g + 0.25 * f.b,
+            cg: -0.25 * f.r + 0.5 * f.g - 0.25 * f.b,
+            co: 0.5 * f.r - 0.5 * f.b
+        }
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalRGBColor> for NormalizedYCgCoColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        NormalizedYCgCoColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedRGBColor> for DigitalYCgCoColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalYCgCoColor::from(NormalizedYCgCoColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalRGBColor> for DigitalYCgCoColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        DigitalYCgCoColor::from(NormalizedYCgCoColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalRGBColor> for DigitalYCoCgRColor {
+    fn from(f: DigitalRGBColor) -> Self {
+        let co = f.r as i16 - f.b as i16;
+        let t = f.b as i16 + (co >> 1);
+        let cg = f.g as i16 - t;
+
+        DigitalYCoCgRColor{
+            y: t + (cg >> 1),
+            co,
+            cg
+        }
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<NormalizedRGBColor> for DigitalYCoCgRColor {
+    fn from(f: NormalizedRGBColor) -> Self {
+        DigitalYCoCgRColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// RGBA to YCgCo
+//
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalRGBAColor> for NormalizedYCgCoColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        NormalizedYCgCoColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedRGBAColor> for NormalizedYCgCoColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        NormalizedYCgCoColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<DigitalRGBAColor> for DigitalYCgCoColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalYCgCoColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using [YCgCo conversion](https://www.itu.int/rec/T-REC-H.264) (ITU-T H.264, table E-5)
+impl From<NormalizedRGBAColor> for DigitalYCgCoColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalYCgCoColor::from(NormalizedRGBColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<DigitalRGBAColor> for DigitalYCoCgRColor {
+    fn from(f: DigitalRGBAColor) -> Self {
+        DigitalYCoCgRColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+/// using the [YCoCg-R lifting scheme](https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/)
+impl From<NormalizedRGBAColor> for DigitalYCoCgRColor {
+    fn from(f: NormalizedRGBAColor) -> Self {
+        DigitalYCoCgRColor::from(DigitalRGBColor::from(f))
+    }
+}
+
+//
+// Color conversion traits
+//
+
+impl YCgCoConvertible for NormalizedYCgCoColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
+    }
+}
+
+impl YCgCoConvertible for DigitalYCoCgRColor {
+    fn convert_vec_ycgco(items: Vec<Self>) -> Vec<DigitalYCgCoColor> {
+        items.into_iter().map(DigitalYCgCoColor::from).collect()
+    }
+}
+
+impl RGBConvertible for DigitalYCgCoColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBConvertible for NormalizedYCgCoColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBConvertible for DigitalYCoCgRColor {
+    fn convert_vec_rgb(items: Vec<Self>) -> Vec<DigitalRGBColor> {
+        items.into_iter().map(DigitalRGBColor::from).collect()
+    }
+
+    fn convert_iter_rgb(items: Box<dyn Iterator<Item = Self>>) ->  Box<dyn Iterator<Item = DigitalRGBColor>> {
+        Box::new(items.map(DigitalRGBColor::from))
+    }
+}
+
+impl RGBAConvertible for DigitalYCgCoColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for NormalizedYCgCoColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+impl RGBAConvertible for DigitalYCoCgRColor {
+    fn convert_vec_rgba(items: Vec<Self>) -> Vec<DigitalRGBAColor> {
+        items.into_iter().map(DigitalRGBAColor::from).collect()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rgb_n_2_ycgco_n() {
+        let r = NormalizedRGBColor{ r: 1.0, g: 0.5, b: 0.0 };
+        let y = NormalizedYCgCoColor::from(r);
+        assert_eq!(NormalizedYCgCoColor{ y: 0.5, cg: 0.0, co: 0.5 }, y);
+    }
+
+    #[test]
+    fn ycgco_n_2_rgb_n() {
+        let y = NormalizedYCgCoColor{ y: 0.5, cg: 0.0, co: 0.5 };
+        let r = NormalizedRGBColor::from(y);
+        assert_eq!(NormalizedRGBColor{ r: 1.0, g: 0.5, b: 0.0 }, r);
+    }
+
+    #[test]
+    fn ycgco_d_2_ycgco_n() {
+        let y = DigitalYCgCoColor{ y: 255, cg: 128, co: 128 };
+        let n = NormalizedYCgCoColor::from(y);
+        assert_eq!(NormalizedYCgCoColor{ y: 1.0, cg: 0.0, co: 0.0 }, n);
+    }
+
+    #[test]
+    fn rgb_d_2_ycgco_d() {
+        let r = DigitalRGBColor{ r: 127, g: 127, b: 127 };
+        let y = DigitalYCgCoColor::from(r);
+        assert_eq!(DigitalYCgCoColor{ y: 127, cg: 128, co: 128 }, y);
+        assert_eq!(r, DigitalRGBColor::from(y));
+    }
+
+    #[test]
+    fn rgb_d_2_ycocgr() {
+        let r = DigitalRGBColor{ r: 255, g: 0, b: 0 };
+        let y = DigitalYCoCgRColor::from(r);
+        assert_eq!(DigitalYCoCgRColor{ y: 63, co: 255, cg: -127 }, y);
+    }
+
+    #[test]
+    fn ycocgr_is_lossless() {
+        for r in (0..=255).step_by(3) {
+            for g in (0..=255).step_by(5) {
+                for b in 0..=255 {
+                    let rgb = DigitalRGBColor{ r, g, b };
+                    assert_eq!(rgb, DigitalRGBColor::from(DigitalYCoCgRColor::from(rgb)));
+                }
+            }
+        }
+    }
+}